@@ -56,6 +56,23 @@ fn bench_large_text(c: &mut Criterion) {
     });
 }
 
+fn bench_corpus_documents(c: &mut Criterion) {
+    let linter = Linter::new();
+    let documents = [
+        ("fiction", include_str!("../tests/corpus/fiction.txt")),
+        ("technical", include_str!("../tests/corpus/technical.txt")),
+        ("academic", include_str!("../tests/corpus/academic.txt")),
+    ];
+
+    let mut group = c.benchmark_group("lint_corpus");
+    for (name, text) in documents {
+        group.bench_with_input(BenchmarkId::from_parameter(name), text, |b, text| {
+            b.iter(|| linter.check(black_box(text)))
+        });
+    }
+    group.finish();
+}
+
 fn bench_warm_all(c: &mut Criterion) {
     c.bench_function("warm_all_checks", |b| {
         // Use the Proselint struct's warm_all method instead of direct engine access
@@ -79,6 +96,18 @@ fn bench_position_tracking(c: &mut Criterion) {
             }
         })
     });
+
+    // One long non-ASCII line, where counting from the line start would be quadratic
+    let long_line = "Café naïve résumé, 日本語 👋 text. ".repeat(2000);
+    let tracker = LineTracker::new(&long_line);
+
+    c.bench_function("position_tracking_long_line", |b| {
+        b.iter(|| {
+            for (offset, _) in long_line.char_indices().step_by(10) {
+                black_box(tracker.offset_to_position(offset));
+            }
+        })
+    });
 }
 
 fn bench_quote_tracking(c: &mut Criterion) {
@@ -158,6 +187,7 @@ criterion_group!(
     bench_small_text,
     bench_medium_text,
     bench_large_text,
+    bench_corpus_documents,
     bench_warm_all,
     bench_position_tracking,
     bench_quote_tracking,
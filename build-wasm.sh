@@ -14,8 +14,14 @@ fi
 # Build target (default: web)
 TARGET="${1:-web}"
 
-echo "📦 Building for target: $TARGET"
-wasm-pack build --release --target "$TARGET"
+# Optional check categories, e.g. FEATURES="typography lexical_illusions" (default: all)
+if [ -n "$FEATURES" ]; then
+    echo "📦 Building for target: $TARGET with categories: $FEATURES"
+    wasm-pack build --release --target "$TARGET" -- --no-default-features --features "$FEATURES"
+else
+    echo "📦 Building for target: $TARGET"
+    wasm-pack build --release --target "$TARGET"
+fi
 
 # Check if wasm-opt is available
 if command -v wasm-opt &> /dev/null; then
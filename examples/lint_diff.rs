@@ -0,0 +1,91 @@
+//! Lint only the lines a change touched
+//!
+//! ```text
+//! git diff main | cargo run --example lint_diff -- --diff -
+//! cargo run --example lint_diff -- --diff changes.patch
+//! cargo run --example lint_diff -- old.md new.md
+//! cargo run --example lint_diff -- --cache .proselint-cache.json --diff changes.patch
+//! ```
+//!
+//! With `--diff`, each file the diff changes is read from the working tree.
+//! With `--cache`, paragraph results are kept in that file between runs.
+//! Exits with status 1 if any changed line has an issue.
+
+use proselint_wasm::{
+    parse_unified_diff, ChangedLines, LintResult, Linter, ResultCache, DEFAULT_CACHE_BYTES,
+};
+use std::io::Read;
+use std::{env, fs, process};
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let mut linter = Linter::new();
+
+    let cache_path = match args.iter().position(|arg| arg == "--cache") {
+        Some(idx) if idx + 1 < args.len() => Some(args.drain(idx..idx + 2).nth(1).unwrap()),
+        Some(_) => fail("--cache needs a file"),
+        None => None,
+    };
+    if let Some(path) = &cache_path {
+        linter.set_cache(ResultCache::load(path, DEFAULT_CACHE_BYTES));
+    }
+
+    let mut found = 0;
+    match args.as_slice() {
+        [flag, source] if flag == "--diff" => {
+            let diff = if source == "-" {
+                let mut diff = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut diff)
+                    .expect("Failed to read diff from stdin");
+                diff
+            } else {
+                read(source)
+            };
+
+            let files = parse_unified_diff(&diff).unwrap_or_else(|e| fail(&e));
+            for file in files {
+                let text = read(&file.path);
+                found += report(&file.path, &linter.check_changed(&text, &file.lines));
+            }
+        }
+        [old, new] => {
+            let changed = ChangedLines::between(&read(old), &read(new));
+            found += report(new, &linter.check_changed(&read(new), &changed));
+        }
+        _ => fail("Usage: lint_diff [--cache <file>] (--diff <patch|-> | <old> <new>)"),
+    }
+
+    if let (Some(path), Some(cache)) = (cache_path, linter.take_cache()) {
+        let stats = cache.stats();
+        eprintln!(
+            "cache: {} hits, {} misses ({:.0}% hit rate)",
+            stats.hits,
+            stats.misses,
+            stats.hit_rate() * 100.0
+        );
+        if let Err(e) = cache.save(&path) {
+            eprintln!("Error writing {}: {}", path, e);
+        }
+    }
+
+    if found > 0 {
+        process::exit(1);
+    }
+}
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| fail(&format!("Error reading {}: {}", path, e)))
+}
+
+fn report(path: &str, results: &[LintResult]) -> usize {
+    for result in results {
+        println!("{}:{}", path, result);
+    }
+    results.len()
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
+}
@@ -0,0 +1,322 @@
+//! Allowlist module for proselint-wasm
+//!
+//! Phrases and patterns whose results are suppressed, such as product names
+//! ("Very Good Ventures") or a personal dictionary loaded from a word list.
+
+use crate::config::id_covers;
+use aho_corasick::AhoCorasick;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Text an allowlist entry matches
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AllowPattern {
+    /// A whole-word phrase, matched ignoring ASCII case
+    Phrase(String),
+    /// A regular expression, matched as written (add `(?i)` to ignore case)
+    Regex(String),
+}
+
+/// One allowlist entry: results lying inside text it matches are suppressed
+///
+/// In JSON an entry is a bare phrase (`"Very Good Ventures"`) or an object with
+/// `phrase` or `regex` and an optional `check` ID or category prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "AllowEntryRepr", into = "AllowEntryRepr")]
+pub struct AllowEntry {
+    /// What the entry matches
+    pub pattern: AllowPattern,
+    /// Only suppress results from this check or category (all checks if `None`)
+    pub check: Option<String>,
+}
+
+/// Serialized form of `AllowEntry`: a plain string for an unscoped phrase
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum AllowEntryRepr {
+    Phrase(String),
+    Entry {
+        #[serde(flatten)]
+        pattern: AllowPattern,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        check: Option<String>,
+    },
+}
+
+impl From<AllowEntryRepr> for AllowEntry {
+    fn from(repr: AllowEntryRepr) -> Self {
+        match repr {
+            AllowEntryRepr::Phrase(phrase) => AllowEntry::phrase(phrase),
+            AllowEntryRepr::Entry { pattern, check } => AllowEntry { pattern, check },
+        }
+    }
+}
+
+impl From<AllowEntry> for AllowEntryRepr {
+    fn from(entry: AllowEntry) -> Self {
+        match entry {
+            AllowEntry {
+                pattern: AllowPattern::Phrase(phrase),
+                check: None,
+            } => AllowEntryRepr::Phrase(phrase),
+            AllowEntry { pattern, check } => AllowEntryRepr::Entry { pattern, check },
+        }
+    }
+}
+
+impl AllowEntry {
+    /// Allow a phrase for every check
+    pub fn phrase(phrase: impl Into<String>) -> Self {
+        Self {
+            pattern: AllowPattern::Phrase(phrase.into()),
+            check: None,
+        }
+    }
+
+    /// Allow text matching a regex for every check
+    pub fn regex(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: AllowPattern::Regex(pattern.into()),
+            check: None,
+        }
+    }
+
+    /// Only suppress results from this check or category
+    pub fn for_check(mut self, check_id: impl Into<String>) -> Self {
+        self.check = Some(check_id.into());
+        self
+    }
+
+    /// Whether the entry applies to results from this check
+    fn applies_to(&self, check_id: &str) -> bool {
+        self.check
+            .as_deref()
+            .is_none_or(|id| id_covers(id, check_id))
+    }
+
+    /// Parse a word list: one entry per line
+    ///
+    /// ```text
+    /// # Comments and blank lines are ignored
+    /// Very Good Ventures
+    /// /\bv\d+(\.\d+)+\b/
+    /// [weasel_words.very] very good
+    /// ```
+    ///
+    /// A line wrapped in slashes is a regex, and a leading `[check.id]` scopes the
+    /// entry to that check or category.
+    pub fn parse_list(list: &str) -> Vec<AllowEntry> {
+        list.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (check, rest) = match line.strip_prefix('[').and_then(|l| l.split_once(']')) {
+                    Some((check, rest)) => (Some(check.trim()), rest.trim()),
+                    None => (None, line),
+                };
+                if rest.is_empty() {
+                    return None;
+                }
+
+                let entry = match rest.strip_prefix('/').and_then(|r| r.strip_suffix('/')) {
+                    Some(pattern) if !pattern.is_empty() => AllowEntry::regex(pattern),
+                    _ => AllowEntry::phrase(rest),
+                };
+                Some(match check {
+                    Some(check) if !check.is_empty() => entry.for_check(check),
+                    _ => entry,
+                })
+            })
+            .collect()
+    }
+
+    /// Error message if the entry's regex doesn't compile
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.pattern {
+            AllowPattern::Regex(pattern) => regex::Regex::new(pattern)
+                .map(|_| ())
+                .map_err(|e| format!("Invalid allowlist regex '{}': {}", pattern, e)),
+            AllowPattern::Phrase(_) => Ok(()),
+        }
+    }
+}
+
+/// Compiled regexes of allowlist entries, keyed by pattern
+/// Kept per `Config` rather than in the check regex cache, which never evicts and
+/// would otherwise collect every pattern users have ever added
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AllowRegexes {
+    compiled: HashMap<String, Regex>,
+}
+
+impl AllowRegexes {
+    /// Compile the regex of every entry (invalid ones are left out)
+    pub(crate) fn new(entries: &[AllowEntry]) -> Self {
+        let compiled = entries
+            .iter()
+            .filter_map(|entry| match &entry.pattern {
+                AllowPattern::Regex(pattern) => {
+                    Regex::new(pattern).ok().map(|re| (pattern.clone(), re))
+                }
+                AllowPattern::Phrase(_) => None,
+            })
+            .collect();
+        Self { compiled }
+    }
+
+    /// The compiled pattern, compiling it now if it was added after `new`
+    fn get(&self, pattern: &str) -> Option<Regex> {
+        match self.compiled.get(pattern) {
+            Some(re) => Some(re.clone()),
+            None => Regex::new(pattern).ok(),
+        }
+    }
+}
+
+/// Spans of one text matched by allowlist entries
+pub(crate) struct AllowedSpans<'a> {
+    /// Matched span and the entry that matched it
+    spans: Vec<(Range<usize>, &'a AllowEntry)>,
+}
+
+impl<'a> AllowedSpans<'a> {
+    /// Find every span of `text` matched by the entries
+    /// Phrases share one Aho-Corasick scan; each regex is its own scan
+    pub(crate) fn new(text: &str, entries: &'a [AllowEntry], regexes: &AllowRegexes) -> Self {
+        let mut spans = Vec::new();
+        if entries.is_empty() {
+            return Self { spans };
+        }
+
+        let phrases: Vec<(&str, &AllowEntry)> = entries
+            .iter()
+            .filter_map(|entry| match &entry.pattern {
+                AllowPattern::Phrase(phrase) if !phrase.trim().is_empty() => {
+                    Some((phrase.trim(), entry))
+                }
+                _ => None,
+            })
+            .collect();
+        let ac = AhoCorasick::builder()
+            .ascii_case_insensitive(true)
+            .build(phrases.iter().map(|(phrase, _)| phrase));
+        if let Ok(ac) = ac {
+            for mat in ac.find_overlapping_iter(text) {
+                if is_whole_word(text, mat.start(), mat.end()) {
+                    spans.push((mat.range(), phrases[mat.pattern().as_usize()].1));
+                }
+            }
+        }
+
+        for entry in entries {
+            if let AllowPattern::Regex(pattern) = &entry.pattern {
+                // Invalid patterns are reported by `Config::validate`
+                if let Some(re) = regexes.get(pattern) {
+                    spans.extend(re.find_iter(text).map(|mat| (mat.range(), entry)));
+                }
+            }
+        }
+
+        Self { spans }
+    }
+
+    /// Whether a result from `check_id` at `start..end` lies inside an allowed span
+    pub(crate) fn suppresses(&self, check_id: &str, start: usize, end: usize) -> bool {
+        self.spans.iter().any(|(span, entry)| {
+            span.start <= start && end <= span.end && entry.applies_to(check_id)
+        })
+    }
+}
+
+/// Whether `start..end` isn't part of a longer word
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    let first = text[start..end].chars().next();
+    let last = text[start..end].chars().next_back();
+
+    let joins = |outer: Option<char>, inner: Option<char>| {
+        outer.is_some_and(char::is_alphanumeric) && inner.is_some_and(char::is_alphanumeric)
+    };
+    !joins(before, first) && !joins(after, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list() {
+        let list = "# Product names\nVery Good Ventures\n\n/\\bv\\d+\\b/\n[weasel_words.very] very good\n[typography]  /\\d+x\\d+/ \n[] stray\n";
+        assert_eq!(
+            AllowEntry::parse_list(list),
+            vec![
+                AllowEntry::phrase("Very Good Ventures"),
+                AllowEntry::regex(r"\bv\d+\b"),
+                AllowEntry::phrase("very good").for_check("weasel_words.very"),
+                AllowEntry::regex(r"\d+x\d+").for_check("typography"),
+                AllowEntry::phrase("stray"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serde_forms() {
+        let entries: Vec<AllowEntry> = serde_json::from_str(
+            r#"["Very Good Ventures", {"regex": "v\\d+"}, {"phrase": "very", "check": "weasel_words"}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                AllowEntry::phrase("Very Good Ventures"),
+                AllowEntry::regex(r"v\d+"),
+                AllowEntry::phrase("very").for_check("weasel_words"),
+            ]
+        );
+
+        let json = serde_json::to_string(&entries).unwrap();
+        assert_eq!(
+            json,
+            r#"["Very Good Ventures",{"regex":"v\\d+"},{"phrase":"very","check":"weasel_words"}]"#
+        );
+    }
+
+    #[test]
+    fn test_allowed_spans() {
+        let entries = vec![
+            AllowEntry::phrase("very good ventures"),
+            AllowEntry::phrase("very").for_check("weasel_words.very"),
+            AllowEntry::regex(r"\d+x\d+"),
+        ];
+        let text = "Very Good Ventures made a 4x6 card. Everyone was very happy.";
+        let spans = AllowedSpans::new(text, &entries, &AllowRegexes::new(&entries));
+
+        // Inside the product name, for any check
+        assert!(spans.suppresses("weasel_words.very", 0, 4));
+        assert!(spans.suppresses("cliches.misc", 5, 9));
+        assert!(spans.suppresses("typography.symbols.multiplication", 26, 29));
+
+        // Scoped phrase only applies to its check, and only to whole words
+        let very = text.rfind("very").unwrap();
+        assert!(spans.suppresses("weasel_words.very", very, very + 4));
+        assert!(!spans.suppresses("hedging.very", very, very + 4));
+        assert!(!spans.suppresses("weasel_words.very_unique", very, very + 4));
+        let every = text.find("Everyone").unwrap();
+        assert!(!spans.suppresses("weasel_words.very", every + 1, every + 5));
+
+        // Entries added after the regexes were compiled still apply
+        let spans = AllowedSpans::new(text, &entries, &AllowRegexes::default());
+        assert!(spans.suppresses("typography.symbols.multiplication", 26, 29));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(AllowEntry::regex(r"\d+").validate().is_ok());
+        assert!(AllowEntry::phrase("(").validate().is_ok());
+        assert!(AllowEntry::regex("(").validate().is_err());
+    }
+}
@@ -0,0 +1,439 @@
+//! Baseline module for proselint-wasm
+//!
+//! A `Baseline` records fingerprints of the results a project already has, so
+//! later runs report only newly introduced issues. Fingerprints are built from
+//! the check ID, the matched text and a few words around it rather than the
+//! line number, so they survive edits elsewhere in the document.
+
+use crate::config::{Config, LintOptions};
+use crate::engine;
+use crate::LintResult;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use wasm_bindgen::prelude::*;
+
+/// Format version written to new baselines
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Words of context taken on each side of a match (within its sentence)
+const CONTEXT_WORDS: usize = 3;
+
+/// Fingerprint of a result from linting `text`
+///
+/// A hex FNV-1a hash of the check ID, the matched text and up to three words on
+/// each side of it within the same sentence, with case and whitespace
+/// normalized. Results with identical text and context share a fingerprint.
+pub fn fingerprint(text: &str, result: &LintResult) -> String {
+    let start = result.start.min(text.len());
+    let end = result.end.clamp(start, text.len());
+    let (Some(before), Some(matched), Some(after)) =
+        (text.get(..start), text.get(start..end), text.get(end..))
+    else {
+        return format!("{:016x}", fnv1a(&[result.check.as_str()]));
+    };
+
+    let before = normalize(&context_before(before));
+    let matched = normalize(&[matched]);
+    let after = normalize(&context_after(after));
+    format!(
+        "{:016x}",
+        fnv1a(&[result.check.as_str(), &before, &matched, &after])
+    )
+}
+
+/// A recorded result: what it was, and how many times it occurred
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Check that reported the result
+    pub check: String,
+    /// Matched text, for reading the baseline file
+    pub text: String,
+    /// Number of results with this fingerprint
+    #[serde(default = "default_count")]
+    pub count: usize,
+}
+
+fn default_count() -> usize {
+    1
+}
+
+/// Fingerprints of known results, to be checked into a project next to its config
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Format version
+    #[serde(default = "default_version")]
+    pub version: u32,
+    /// Entries keyed by fingerprint (see `fingerprint`)
+    #[serde(default)]
+    pub entries: BTreeMap<String, BaselineEntry>,
+}
+
+fn default_version() -> u32 {
+    BASELINE_VERSION
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl Baseline {
+    /// Create an empty baseline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a baseline holding the results from linting one text
+    pub fn from_results(text: &str, results: &[LintResult]) -> Self {
+        let mut baseline = Self::new();
+        baseline.record(text, results);
+        baseline
+    }
+
+    /// Add the results from linting `text`; call once per document
+    pub fn record(&mut self, text: &str, results: &[LintResult]) {
+        for result in results {
+            self.entries
+                .entry(fingerprint(text, result))
+                .and_modify(|entry| entry.count += 1)
+                .or_insert_with(|| BaselineEntry {
+                    check: result.check.clone(),
+                    text: text.get(result.span()).unwrap_or_default().to_string(),
+                    count: 1,
+                });
+        }
+    }
+
+    /// Number of results recorded
+    pub fn len(&self) -> usize {
+        self.entries.values().map(|entry| entry.count).sum()
+    }
+
+    /// True if no results are recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Keep only the results from linting `text` that aren't in the baseline
+    /// For many documents, use one `BaselineMatcher` so stale entries are counted once
+    pub fn filter(&self, text: &str, results: Vec<LintResult>) -> BaselineReport {
+        let mut remaining = self.counts();
+        let mut matched = 0;
+        let results = take_unmatched(&mut remaining, &mut matched, text, results);
+        BaselineReport {
+            results,
+            matched,
+            stale: remaining.values().sum(),
+        }
+    }
+
+    /// Occurrences recorded per fingerprint
+    fn counts(&self) -> HashMap<String, usize> {
+        self.entries
+            .iter()
+            .map(|(fingerprint, entry)| (fingerprint.clone(), entry.count))
+            .collect()
+    }
+}
+
+/// Results of a lint compared against a baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineReport {
+    /// Results not in the baseline
+    pub results: Vec<LintResult>,
+    /// Results hidden because the baseline has them
+    pub matched: usize,
+    /// Baseline results that no longer occur (fixed, or their text changed)
+    pub stale: usize,
+}
+
+/// Compares the results of several documents against one baseline
+///
+/// Each baseline entry is matched at most `count` times across every call to
+/// `filter`, so entries left over afterwards no longer occur anywhere.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct BaselineMatcher {
+    baseline: Baseline,
+    /// Unmatched occurrences left per fingerprint
+    remaining: HashMap<String, usize>,
+    matched: usize,
+}
+
+impl BaselineMatcher {
+    /// Start comparing results against `baseline`
+    pub fn new(baseline: Baseline) -> Self {
+        let remaining = baseline.counts();
+        Self {
+            baseline,
+            remaining,
+            matched: 0,
+        }
+    }
+
+    /// Keep only the results from linting `text` that aren't in the baseline
+    pub fn filter(&mut self, text: &str, results: Vec<LintResult>) -> Vec<LintResult> {
+        take_unmatched(&mut self.remaining, &mut self.matched, text, results)
+    }
+
+    /// The baseline without its stale entries, to write back so it shrinks
+    pub fn pruned(&self) -> Baseline {
+        let mut baseline = self.baseline.clone();
+        baseline.entries.retain(|fingerprint, entry| {
+            entry.count -= self.remaining.get(fingerprint).copied().unwrap_or(0);
+            entry.count > 0
+        });
+        baseline
+    }
+}
+
+#[wasm_bindgen]
+impl BaselineMatcher {
+    /// Results hidden so far because the baseline has them
+    #[wasm_bindgen]
+    pub fn matched(&self) -> usize {
+        self.matched
+    }
+
+    /// Baseline results not seen so far
+    #[wasm_bindgen]
+    pub fn stale(&self) -> usize {
+        self.remaining.values().sum()
+    }
+}
+
+/// Record every result of linting each text, ignoring `max_errors`
+pub(crate) fn lint_baseline<'a>(
+    texts: impl IntoIterator<Item = &'a str>,
+    config: &Config,
+) -> Baseline {
+    let mut baseline = Baseline::new();
+    for text in texts {
+        baseline.record(text, &lint_all(text, config));
+    }
+    baseline
+}
+
+/// Lint `text` and keep the results that aren't in `baseline`
+/// `max_errors` applies to the new results, not to everything the lint found
+pub(crate) fn lint_new(text: &str, config: &Config, baseline: &Baseline) -> BaselineReport {
+    let mut report = baseline.filter(text, lint_all(text, config));
+    if config.max_errors > 0 {
+        report.results.truncate(config.max_errors);
+    }
+    report
+}
+
+/// Lint `text` without the `max_errors` limit
+fn lint_all(text: &str, config: &Config) -> Vec<LintResult> {
+    let options = LintOptions {
+        max_errors: Some(0),
+        ..Default::default()
+    };
+    engine::lint_with_options(text, config, &options)
+}
+
+/// Drop results with occurrences left in `remaining`, using up one each
+fn take_unmatched(
+    remaining: &mut HashMap<String, usize>,
+    matched: &mut usize,
+    text: &str,
+    results: Vec<LintResult>,
+) -> Vec<LintResult> {
+    results
+        .into_iter()
+        .filter(
+            |result| match remaining.get_mut(&fingerprint(text, result)) {
+                Some(left) if *left > 0 => {
+                    *left -= 1;
+                    *matched += 1;
+                    false
+                }
+                _ => true,
+            },
+        )
+        .collect()
+}
+
+/// Up to `CONTEXT_WORDS` words at the end of `before`, within its sentence and paragraph
+fn context_before(before: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    'lines: for (idx, line) in before.rsplit('\n').enumerate() {
+        if idx > 0 && line.trim().is_empty() {
+            break;
+        }
+        for word in line.split_whitespace().rev() {
+            if words.len() == CONTEXT_WORDS || ends_sentence(word) {
+                break 'lines;
+            }
+            words.push(word);
+        }
+    }
+    words.reverse();
+    words
+}
+
+/// Up to `CONTEXT_WORDS` words at the start of `after`, within its sentence and paragraph
+fn context_after(after: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    'lines: for (idx, line) in after.split('\n').enumerate() {
+        if idx > 0 && line.trim().is_empty() {
+            break;
+        }
+        for word in line.split_whitespace() {
+            if words.len() == CONTEXT_WORDS {
+                break 'lines;
+            }
+            words.push(word);
+            if ends_sentence(word) {
+                break 'lines;
+            }
+        }
+    }
+    words
+}
+
+/// Whether a word ends with sentence punctuation, ignoring closing quotes and brackets
+fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(['"', '\'', '\u{201D}', '\u{2019}', ')', ']'])
+        .ends_with(['.', '!', '?'])
+}
+
+/// Lowercase the pieces and collapse their whitespace to single spaces
+fn normalize(pieces: &[&str]) -> String {
+    pieces
+        .iter()
+        .flat_map(|piece| piece.split_whitespace())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 64-bit FNV-1a over the parts, separated by NUL bytes
+/// Stable across builds and platforms, unlike `std::hash`
+pub(crate) fn fnv1a(parts: &[&str]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (idx, part) in parts.iter().enumerate() {
+        if idx > 0 {
+            // The NUL separator: XOR with zero leaves the hash unchanged
+            hash = hash.wrapping_mul(PRIME);
+        }
+        for byte in part.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(check: &str, text: &str, needle: &str, nth: usize) -> LintResult {
+        let start = text.match_indices(needle).nth(nth).unwrap().0;
+        LintResult {
+            check: check.to_string(),
+            message: String::new(),
+            line: 1,
+            column: 1,
+            end_line: 1,
+            end_column: 1,
+            start,
+            end: start + needle.len(),
+            severity: "warning".to_string(),
+            replacement: None,
+            related: Vec::new(),
+            merged: Vec::new(),
+            utf16_start: None,
+            utf16_end: None,
+            char_start: None,
+            char_end: None,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_position() {
+        let text = "It was a very long day.";
+        let moved = "A new opening paragraph.\n\nIt was a   VERY long\nday.";
+        assert_eq!(
+            fingerprint(text, &result("weasel_words.very", text, "very", 0)),
+            fingerprint(moved, &result("weasel_words.very", moved, "VERY", 0))
+        );
+
+        // Context and check ID tell results apart
+        let other = "It was a very short day.";
+        assert_ne!(
+            fingerprint(text, &result("weasel_words.very", text, "very", 0)),
+            fingerprint(other, &result("weasel_words.very", other, "very", 0))
+        );
+        assert_ne!(
+            fingerprint(text, &result("weasel_words.very", text, "very", 0)),
+            fingerprint(text, &result("hedging.very", text, "very", 0))
+        );
+
+        // Context stops at the sentence and paragraph break
+        let before = "First.\n\nvery good. Then more";
+        let changed = "Changed\n\nvery good. Then less";
+        assert_eq!(
+            fingerprint(before, &result("weasel_words.very", before, "very", 0)),
+            fingerprint(changed, &result("weasel_words.very", changed, "very", 0))
+        );
+    }
+
+    #[test]
+    fn test_filter_counts_occurrences() {
+        let text = "It is very good. It is very good.";
+        let results = vec![
+            result("weasel_words.very", text, "very", 0),
+            result("weasel_words.very", text, "very", 1),
+        ];
+        let baseline = Baseline::from_results(text, &results[..1]);
+        assert_eq!(baseline.len(), 1);
+
+        // Only one of the two identical sentences is in the baseline
+        let report = baseline.filter(text, results.clone());
+        assert_eq!(report.results, vec![results[1].clone()]);
+        assert_eq!((report.matched, report.stale), (1, 0));
+
+        // Fixed: the entry is stale and pruning drops it
+        let fixed = "It is good.";
+        let mut matcher = BaselineMatcher::new(baseline);
+        assert!(matcher.filter(fixed, Vec::new()).is_empty());
+        assert_eq!(matcher.stale(), 1);
+        assert!(matcher.pruned().is_empty());
+    }
+
+    #[test]
+    fn test_matcher_across_documents() {
+        let first = "A very old page.";
+        let second = "Another very old page.";
+        let mut baseline = Baseline::new();
+        baseline.record(first, &[result("weasel_words.very", first, "very", 0)]);
+        baseline.record(second, &[result("weasel_words.very", second, "very", 0)]);
+        assert_eq!(baseline.entries.len(), 2);
+
+        let mut matcher = BaselineMatcher::new(baseline.clone());
+        let edited = "A very new page.";
+        assert_eq!(
+            matcher
+                .filter(edited, vec![result("weasel_words.very", edited, "very", 0)])
+                .len(),
+            1
+        );
+        assert!(matcher
+            .filter(second, vec![result("weasel_words.very", second, "very", 0)])
+            .is_empty());
+        assert_eq!((matcher.matched(), matcher.stale()), (1, 1));
+        assert_eq!(matcher.pruned().len(), 1);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
@@ -0,0 +1,538 @@
+//! Cache module for proselint-wasm
+//!
+//! `ResultCache` remembers each paragraph's results under a hash of its text,
+//! the `Config` and the check registry, so boilerplate repeated across pages
+//! and files an editor re-lints unchanged are only linted once. Results are
+//! stored relative to their paragraph and shifted to its position on reuse.
+//! Checks that read the whole document are not cached and run on every call.
+
+use crate::baseline::fnv1a;
+use crate::checks;
+use crate::config::{Config, LintOptions};
+use crate::engine::{self, Scope};
+use crate::job::paragraph_chunks;
+use crate::position::LineTracker;
+use crate::LintResult;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Memory budget of `ResultCache::default()` (16 MB)
+pub const DEFAULT_CACHE_BYTES: usize = 16 * 1024 * 1024;
+
+/// Format version of saved caches; others are ignored on load
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+/// Estimated bytes per entry on top of its results (key and map bookkeeping)
+const ENTRY_OVERHEAD: usize = 64;
+
+/// Hit-rate statistics for a `ResultCache`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Paragraphs whose results came from the cache
+    pub hits: u64,
+    /// Paragraphs that had to be linted
+    pub misses: u64,
+    /// Entries dropped to stay within the memory budget
+    pub evictions: u64,
+    /// Entries held
+    pub entries: usize,
+    /// Estimated memory held by the entries
+    pub bytes: usize,
+    /// Memory budget
+    pub max_bytes: usize,
+}
+
+impl CacheStats {
+    /// Share of paragraphs served from the cache, from 0 to 1
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+/// Bounded least-recently-used cache of results per paragraph
+///
+/// Only paragraph-local results are cached. Document-wide checks (repetition, and
+/// dialect checks in "any" mode, whose majority is taken over the document) run
+/// on the whole text on every call, and overlaps are merged afterwards.
+/// Paragraphs are split where a blank line ends a prose paragraph outside any
+/// quotation, so a pattern match that would span that blank line (such as
+/// "very\n\nunique") is not found, and cached results can miss it where an
+/// uncached lint reports it.
+#[derive(Debug, Clone)]
+pub struct ResultCache {
+    max_bytes: usize,
+    entries: HashMap<u64, CacheEntry>,
+    /// Keys by last use, oldest first
+    recency: BTreeMap<u64, u64>,
+    /// Incremented on every use, so recency keys are unique
+    tick: u64,
+    stats: CacheStats,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    /// Results with offsets and lines relative to the paragraph start
+    results: Vec<LintResult>,
+    size: usize,
+    last_used: u64,
+}
+
+/// Saved form of a cache: entries oldest first, so loading keeps their recency
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: Vec<CacheFileEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFileEntry {
+    key: String,
+    results: Vec<LintResult>,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_BYTES)
+    }
+}
+
+impl ResultCache {
+    /// Create an empty cache holding about `max_bytes` of results
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: CacheStats {
+                max_bytes,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Lint `text` with `config`, reusing cached results for paragraphs seen before
+    pub fn lint(&mut self, text: &str, config: &Config) -> Vec<LintResult> {
+        let mut results = self.lint_all(text, config);
+        if config.max_errors > 0 {
+            results.truncate(config.max_errors);
+        }
+        results
+    }
+
+    /// Like `lint`, but returns every result whatever `max_errors` is set to
+    pub(crate) fn lint_all(&mut self, text: &str, config: &Config) -> Vec<LintResult> {
+        let tracker = LineTracker::new(text);
+        self.lint_span(text, 0..text.len(), &tracker, config)
+    }
+
+    /// Like `engine::lint_span` with every result kept, reusing cached paragraphs
+    pub(crate) fn lint_span(
+        &mut self,
+        text: &str,
+        span: Range<usize>,
+        tracker: &LineTracker,
+        config: &Config,
+    ) -> Vec<LintResult> {
+        let setup = setup_fingerprint(config);
+        let options = LintOptions {
+            max_errors: Some(0),
+            ..Default::default()
+        };
+
+        let mut results = Vec::new();
+        for chunk in paragraph_chunks(text, span.clone(), 0) {
+            let key = fnv1a(&[&setup, &text[chunk.clone()]]);
+            let origin = Origin::at(tracker, chunk.start);
+
+            if let Some(cached) = self.get(key) {
+                results.extend(cached.iter().map(|r| rebase(r, &Origin::ZERO, &origin)));
+                self.stats.hits += 1;
+            } else {
+                self.stats.misses += 1;
+                let fresh =
+                    engine::run_checks(text, chunk, tracker, config, &options, Scope::Paragraph);
+                self.insert(
+                    key,
+                    fresh
+                        .iter()
+                        .map(|r| rebase(r, &origin, &Origin::ZERO))
+                        .collect(),
+                );
+                results.extend(fresh);
+            }
+        }
+
+        if engine::runs_any(Scope::Document, config, &options) {
+            results.extend(engine::run_checks(
+                text,
+                span,
+                tracker,
+                config,
+                &options,
+                Scope::Document,
+            ));
+        }
+        engine::finish_results(results, tracker, config, &options)
+    }
+
+    /// Hit-rate and memory statistics
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Number of cached paragraphs
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True if nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop every entry (statistics other than memory use are kept)
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.stats.entries = 0;
+        self.stats.bytes = 0;
+    }
+
+    /// Serialize the entries, e.g. to keep them between CLI runs
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .recency
+            .values()
+            .map(|key| CacheFileEntry {
+                key: format!("{:016x}", key),
+                results: self.entries[key].results.clone(),
+            })
+            .collect();
+        let file = CacheFile {
+            version: CACHE_FORMAT_VERSION,
+            entries,
+        };
+        serde_json::to_string(&file).unwrap_or_default()
+    }
+
+    /// Restore a cache saved with `to_json`, keeping the most recent entries that fit
+    /// A cache saved by another format version loads empty
+    pub fn from_json(json: &str, max_bytes: usize) -> Result<Self, String> {
+        let file: CacheFile =
+            serde_json::from_str(json).map_err(|e| format!("Invalid cache: {}", e))?;
+        let mut cache = Self::new(max_bytes);
+        if file.version != CACHE_FORMAT_VERSION {
+            return Ok(cache);
+        }
+        for entry in file.entries {
+            let key = u64::from_str_radix(&entry.key, 16)
+                .map_err(|e| format!("Invalid cache key '{}': {}", entry.key, e))?;
+            cache.insert(key, entry.results);
+        }
+        cache.stats.evictions = 0;
+        Ok(cache)
+    }
+
+    /// Write the cache to a file (see `to_json`)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Read a cache written by `save`; a missing or unreadable file gives an empty cache
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: impl AsRef<std::path::Path>, max_bytes: usize) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|json| Self::from_json(&json, max_bytes).ok())
+            .unwrap_or_else(|| Self::new(max_bytes))
+    }
+
+    /// Look up an entry and mark it as just used
+    fn get(&mut self, key: u64) -> Option<&[LintResult]> {
+        let entry = self.entries.get_mut(&key)?;
+        self.tick += 1;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.tick, key);
+        entry.last_used = self.tick;
+        Some(&entry.results)
+    }
+
+    /// Add an entry, evicting the least recently used ones to stay within budget
+    fn insert(&mut self, key: u64, results: Vec<LintResult>) {
+        let size = ENTRY_OVERHEAD + results.iter().map(result_size).sum::<usize>();
+        if size > self.max_bytes {
+            return;
+        }
+
+        self.tick += 1;
+        let entry = CacheEntry {
+            results,
+            size,
+            last_used: self.tick,
+        };
+        if let Some(old) = self.entries.insert(key, entry) {
+            self.recency.remove(&old.last_used);
+            self.stats.bytes -= old.size;
+        }
+        self.recency.insert(self.tick, key);
+        self.stats.bytes += size;
+
+        while self.stats.bytes > self.max_bytes {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            if let Some(evicted) = self.entries.remove(&oldest) {
+                self.stats.bytes -= evicted.size;
+                self.stats.evictions += 1;
+            }
+        }
+        self.stats.entries = self.entries.len();
+    }
+}
+
+/// Position of a paragraph start, in every unit a result carries
+#[derive(Debug, Clone, Copy)]
+struct Origin {
+    byte: usize,
+    /// Lines before the paragraph
+    lines: usize,
+    utf16: usize,
+    chars: usize,
+}
+
+impl Origin {
+    const ZERO: Origin = Origin {
+        byte: 0,
+        lines: 0,
+        utf16: 0,
+        chars: 0,
+    };
+
+    fn at(tracker: &LineTracker, offset: usize) -> Self {
+        Self {
+            byte: offset,
+            lines: tracker.offset_to_position(offset).0 - 1,
+            utf16: tracker.utf16_offset(offset),
+            chars: tracker.char_offset(offset),
+        }
+    }
+}
+
+/// Move a result from one paragraph start to another
+/// Paragraphs start at the beginning of a line, so columns stay the same
+fn rebase(result: &LintResult, from: &Origin, to: &Origin) -> LintResult {
+    let byte = |offset: usize| offset - from.byte + to.byte;
+    LintResult {
+        line: result.line - from.lines + to.lines,
+        end_line: result.end_line - from.lines + to.lines,
+        start: byte(result.start),
+        end: byte(result.end),
+        related: result
+            .related
+            .iter()
+            .map(|span| byte(span.start)..byte(span.end))
+            .collect(),
+        utf16_start: result.utf16_start.map(|o| o - from.utf16 + to.utf16),
+        utf16_end: result.utf16_end.map(|o| o - from.utf16 + to.utf16),
+        char_start: result.char_start.map(|o| o - from.chars + to.chars),
+        char_end: result.char_end.map(|o| o - from.chars + to.chars),
+        ..result.clone()
+    }
+}
+
+/// Estimated memory held by a result
+fn result_size(result: &LintResult) -> usize {
+    std::mem::size_of::<LintResult>()
+        + result.check.len()
+        + result.message.len()
+        + result.severity.len()
+        + result.replacement.as_ref().map_or(0, String::len)
+        + result.related.len() * std::mem::size_of::<std::ops::Range<usize>>()
+        + result.merged.iter().map(String::len).sum::<usize>()
+}
+
+/// Hash of everything besides the text that decides a paragraph's results
+fn setup_fingerprint(config: &Config) -> String {
+    // Going through `Value` sorts map keys, so equal configs hash alike
+    let config = serde_json::to_value(config)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    format!("{:016x}", fnv1a(&[registry_fingerprint(), &config]))
+}
+
+/// Hash of the crate version and every registered check's definition
+fn registry_fingerprint() -> &'static str {
+    static FINGERPRINT: OnceLock<String> = OnceLock::new();
+    FINGERPRINT.get_or_init(|| {
+        let mut parts = vec![env!("CARGO_PKG_VERSION")];
+        for check in checks::get_all_checks() {
+            parts.extend([
+                check.id,
+                check.pattern,
+                check.message,
+                check.severity.as_str(),
+                check.replacement.unwrap_or_default(),
+            ]);
+        }
+        format!("{:016x}", fnv1a(&parts))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOILERPLATE: &str = "This page is very much a work in progress.\n\n";
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_cached_results_match_lint() {
+        let mut config = Config::default();
+        config.utf16_offsets = true;
+        config.char_offsets = true;
+
+        let first = format!("# Intro 👋\n\n{}It was very unique.\n", BOILERPLATE);
+        let second = format!("Другая страница.\n\nShort.\n\n{}", BOILERPLATE);
+
+        let mut cache = ResultCache::default();
+        assert_eq!(
+            cache.lint(&first, &config),
+            engine::lint_text(&first, &config)
+        );
+        assert_eq!(cache.stats().hits, 0);
+
+        // The boilerplate paragraph is reused at a new position
+        let results = cache.lint(&second, &config);
+        assert_eq!(results, engine::lint_text(&second, &config));
+        assert!(results.iter().any(|r| &second[r.span()] == "very"));
+        assert_eq!(cache.stats().hits, 1);
+
+        // Unchanged file: every paragraph is a hit
+        let misses = cache.stats().misses;
+        assert_eq!(
+            cache.lint(&first, &config),
+            engine::lint_text(&first, &config)
+        );
+        assert_eq!(cache.stats().misses, misses);
+        assert_eq!(cache.stats().hits, 4);
+        assert_eq!(cache.stats().hit_rate(), 4.0 / 9.0);
+    }
+
+    #[cfg(all(feature = "uncomparables", feature = "weasel_words"))]
+    #[test]
+    fn test_cached_results_across_paragraphs() {
+        let text = concat!(
+            "It was very unique.\n\n",
+            "The plan is very\n\nunique, and very good.\n\n",
+            "Nothing to see here.\n",
+        );
+        let config = Config::default();
+        let uncached = engine::lint_text(text, &config);
+        let mut cache = ResultCache::default();
+        let cached = cache.lint(text, &config);
+
+        // Only the match spanning the blank line after "very" is missing
+        let spanning: Vec<_> = uncached
+            .iter()
+            .filter(|r| text[r.span()].contains("\n\n"))
+            .collect();
+        assert_eq!(spanning.len(), 1);
+        assert_eq!(spanning[0].check, "uncomparables.very_unique");
+        let rest: Vec<_> = uncached
+            .iter()
+            .filter(|r| !text[r.span()].contains("\n\n"))
+            .cloned()
+            .collect();
+        assert_eq!(cached, rest);
+        assert_eq!(cache.lint(text, &config), cached);
+    }
+
+    #[cfg(all(feature = "dialects", feature = "weasel_words"))]
+    #[test]
+    fn test_document_wide_results_match_lint() {
+        use crate::dialect::Dialect;
+
+        let text = concat!(
+            "The colour of the sea was very grey.\n\n",
+            "The color of the sky, the color of the hills.\n\n",
+            "```\nlet very = 1;\n\n// very unique\n```\n\n",
+            "\u{201C}It was very odd,\n\n\u{201C}and then very quiet.\u{201D}\n\n",
+            "The budget was tight. We trimmed the budget. Budgets matter to budget holders.\n",
+        );
+
+        let mut any = Config::with_dialect(Dialect::Any);
+        any.enable("repetition");
+        let mut outside_quotes = Config::default();
+        outside_quotes.check_quotes = false;
+        for config in [
+            Config::default(),
+            Config::all_enabled(),
+            any,
+            outside_quotes,
+        ] {
+            let expected = engine::lint_text(text, &config);
+            let mut cache = ResultCache::default();
+            assert_eq!(cache.lint(text, &config), expected);
+            assert_eq!(cache.lint(text, &config), expected);
+            assert_eq!(cache.stats().misses, cache.stats().hits);
+        }
+    }
+
+    #[test]
+    fn test_config_changes_miss() {
+        let mut cache = ResultCache::default();
+        let mut config = Config::default();
+        cache.lint(BOILERPLATE, &config);
+        config.disable("weasel_words");
+        assert!(cache.lint(BOILERPLATE, &config).is_empty());
+        assert_eq!(cache.stats().hits, 0);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_memory_bound() {
+        let config = Config::default();
+        let text: String = (0..50)
+            .map(|i| format!("Paragraph {} is very, very long.\n\n", i))
+            .collect();
+
+        let mut cache = ResultCache::new(4096);
+        cache.lint(&text, &config);
+        let stats = cache.stats();
+        assert!(stats.bytes <= 4096);
+        assert!(stats.evictions > 0);
+        assert_eq!(stats.entries, cache.len());
+
+        // The most recent paragraphs survived
+        let last = format!("Paragraph {} is very, very long.\n\n", 49);
+        cache.lint(&last, &config);
+        assert_eq!(cache.stats().hits, 1);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let config = Config::default();
+        let mut cache = ResultCache::default();
+        let expected = cache.lint(BOILERPLATE, &config);
+
+        let mut restored = ResultCache::from_json(&cache.to_json(), DEFAULT_CACHE_BYTES).unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored.lint(BOILERPLATE, &config), expected);
+        assert_eq!(restored.stats().hits, 1);
+
+        let other_version = r#"{"version": 0, "entries": [{"key": "1", "results": []}]}"#;
+        assert!(ResultCache::from_json(other_version, 1024)
+            .unwrap()
+            .is_empty());
+        assert!(ResultCache::from_json("not json", 1024).is_err());
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! Defines the Check trait and common check types.
 
+use crate::config::Config;
+use crate::dialect::{Convention, Variant};
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::ops::Range;
 use std::str::FromStr;
 use std::sync::{Arc, OnceLock, RwLock};
 
@@ -41,8 +45,9 @@ pub fn get_cached_regex(pattern: &str) -> Option<Arc<Regex>> {
 }
 
 /// Severity levels for lint results
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[derive(Default)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     /// Suggestions are optional style improvements
     Suggestion,
@@ -89,6 +94,35 @@ impl FromStr for Severity {
 }
 
 
+/// A single match found by a check
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CheckMatch {
+    /// Start position in the text (0-indexed byte offset)
+    pub start: usize,
+    /// End position in the text (0-indexed byte offset)
+    pub end: usize,
+    /// Optional replacement text
+    pub replacement: Option<String>,
+    /// Message overriding the check's own (e.g., to include a measured value)
+    pub message: Option<String>,
+    /// Other spans tied to this match (e.g., earlier occurrences of a repeated word)
+    pub related: Vec<Range<usize>>,
+}
+
+impl CheckMatch {
+    /// Create a match covering `start..end`
+    pub fn new(start: usize, end: usize) -> Self {
+        Self {
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+}
+
+/// A hand-written matcher for checks that can't be expressed as a regex
+pub type Matcher = fn(&str, &Config) -> Vec<CheckMatch>;
+
 /// A single check definition with lazy-compiled regex
 pub struct Check {
     /// Unique identifier (e.g., "typography.symbols.ellipsis")
@@ -101,10 +135,30 @@ pub struct Check {
     pub severity: Severity,
     /// Whether this check can match inside quotes
     pub allow_quotes: bool,
+    /// Whether this check can match inside URLs, paths, identifiers and other protected spans
+    pub allow_protected: bool,
     /// Optional replacement text (can contain capture groups like $1)
     pub replacement: Option<&'static str>,
     /// Whether to use raw pattern (no word boundaries)
     pub raw_pattern: bool,
+    /// Dialect variant this check flags (only runs when the dialect prefers the other side)
+    pub dialect: Option<(Convention, Variant)>,
+    /// Custom matcher used instead of the regex pattern
+    pub matcher: Option<Matcher>,
+    /// Whether results depend on text outside the paragraph (counts over the document)
+    pub document_wide: bool,
+    /// Check IDs or category prefixes whose overlapping results this check replaces
+    pub supersedes: &'static [&'static str],
+    /// Longer explanation of why the check exists (defaults to the category's)
+    pub explanation: Option<&'static str>,
+    /// Style guide or tool the check comes from (defaults to the category's)
+    pub source: Option<&'static str>,
+    /// Free-form tags for filtering in settings UIs
+    pub tags: &'static [&'static str],
+    /// Texts this check flags
+    pub bad_examples: &'static [&'static str],
+    /// Texts this check leaves alone
+    pub good_examples: &'static [&'static str],
     /// Compiled regex - initialized ONCE per Check, not per call
     compiled_regex: OnceLock<Option<Regex>>,
 }
@@ -118,12 +172,30 @@ impl Check {
             pattern,
             severity: Severity::Warning,
             allow_quotes: false,
+            allow_protected: false,
             replacement: None,
             raw_pattern: false,
+            dialect: None,
+            matcher: None,
+            document_wide: false,
+            supersedes: &[],
+            explanation: None,
+            source: None,
+            tags: &[],
+            bad_examples: &[],
+            good_examples: &[],
             compiled_regex: OnceLock::new(),
         }
     }
 
+    /// Create a check driven by a custom matcher instead of a regex pattern
+    /// Matcher checks have no keyword, so they run on every lint call
+    pub const fn with_matcher(id: &'static str, message: &'static str, matcher: Matcher) -> Self {
+        let mut check = Self::new(id, message, "").raw();
+        check.matcher = Some(matcher);
+        check
+    }
+
     /// Create a check with custom severity
     pub const fn with_severity(mut self, severity: Severity) -> Self {
         self.severity = severity;
@@ -136,6 +208,12 @@ impl Check {
         self
     }
 
+    /// Allow matching inside protected spans (see `ProtectedSpanTracker`)
+    pub const fn allow_in_protected(mut self) -> Self {
+        self.allow_protected = true;
+        self
+    }
+
     /// Add a replacement suggestion
     pub const fn with_replacement(mut self, replacement: &'static str) -> Self {
         self.replacement = Some(replacement);
@@ -148,6 +226,76 @@ impl Check {
         self
     }
 
+    /// Mark this check as flagging one side of a dialect variant pair
+    pub const fn dialect(mut self, convention: Convention, variant: Variant) -> Self {
+        self.dialect = Some((convention, variant));
+        self
+    }
+
+    /// Mark this check as reading the whole text rather than one paragraph at a time
+    /// `ResultCache` runs such checks on every call instead of caching them per paragraph
+    pub const fn document_wide(mut self) -> Self {
+        self.document_wide = true;
+        self
+    }
+
+    /// Declare checks (by ID or category prefix) that this check replaces when both
+    /// flag the same text
+    pub const fn supersedes(mut self, ids: &'static [&'static str]) -> Self {
+        self.supersedes = ids;
+        self
+    }
+
+    /// Add a longer explanation of what the check flags and why
+    pub const fn explain(mut self, explanation: &'static str) -> Self {
+        self.explanation = Some(explanation);
+        self
+    }
+
+    /// Cite the style guide or tool this check comes from
+    pub const fn cite(mut self, source: &'static str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Add tags for filtering in settings UIs
+    pub const fn tags(mut self, tags: &'static [&'static str]) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Add texts this check flags (`bad`) and leaves alone (`good`)
+    pub const fn examples(
+        mut self,
+        bad: &'static [&'static str],
+        good: &'static [&'static str],
+    ) -> Self {
+        self.bad_examples = bad;
+        self.good_examples = good;
+        self
+    }
+
+    /// The check's category (the first segment of its ID)
+    pub fn category(&self) -> &'static str {
+        self.id.split('.').next().unwrap_or(self.id)
+    }
+
+    /// Returns true if this check supersedes the check with the given ID
+    pub fn supersedes_check(&self, id: &str) -> bool {
+        self.supersedes.iter().any(|&prefix| {
+            id == prefix || (id.starts_with(prefix) && id[prefix.len()..].starts_with('.'))
+        })
+    }
+
+    /// The pattern as compiled: case-insensitive, and wrapped in word boundaries unless raw
+    pub(crate) fn full_pattern(&self) -> String {
+        if self.raw_pattern {
+            format!(r"(?i){}", self.pattern)
+        } else {
+            format!(r"(?i)\b{}\b", self.pattern)
+        }
+    }
+
     /// Get the compiled regex - computed ONCE per Check lifetime
     /// No allocations after first call!
     ///
@@ -156,11 +304,7 @@ impl Check {
     pub fn get_regex(&self) -> Option<&Regex> {
         self.compiled_regex
             .get_or_init(|| {
-                let pattern = if self.raw_pattern {
-                    format!(r"(?i){}", self.pattern)
-                } else {
-                    format!(r"(?i)\b{}\b", self.pattern)
-                };
+                let pattern = self.full_pattern();
                 match Regex::new(&pattern) {
                     Ok(re) => Some(re),
                     Err(e) => {
@@ -179,11 +323,7 @@ impl Check {
     /// Validate that this check's regex pattern compiles successfully
     /// Returns Ok(()) if valid, or Err with error message if invalid
     pub fn validate_regex(&self) -> Result<(), String> {
-        let pattern = if self.raw_pattern {
-            format!(r"(?i){}", self.pattern)
-        } else {
-            format!(r"(?i)\b{}\b", self.pattern)
-        };
+        let pattern = self.full_pattern();
 
         Regex::new(&pattern).map(|_| ()).map_err(|e| {
             format!(
@@ -197,12 +337,27 @@ impl Check {
     /// After warm-up, this does ZERO allocations for pattern/regex lookup
     #[inline]
     pub fn run(&self, text: &str) -> Vec<(usize, usize, Option<String>)> {
+        self.find_matches(text, &Config::default())
+            .into_iter()
+            .map(|m| (m.start, m.end, m.replacement))
+            .collect()
+    }
+
+    /// Run this check on text with the given configuration
+    /// Custom matchers see the config; regex checks ignore it
+    pub fn find_matches(&self, text: &str, config: &Config) -> Vec<CheckMatch> {
+        if let Some(matcher) = self.matcher {
+            return matcher(text, config);
+        }
+
         let mut results = Vec::new();
 
         if let Some(re) = self.get_regex() {
             for mat in re.find_iter(text) {
-                let replacement = self.replacement.map(|r| r.to_string());
-                results.push((mat.start(), mat.end(), replacement));
+                results.push(CheckMatch {
+                    replacement: self.replacement.map(|r| r.to_string()),
+                    ..CheckMatch::new(mat.start(), mat.end())
+                });
             }
         }
 
@@ -210,6 +365,30 @@ impl Check {
     }
 }
 
+/// Descriptive metadata for a check, for settings UIs and documentation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckInfo {
+    /// Check identifier (e.g., "typography.symbols.ellipsis")
+    pub id: String,
+    /// Category (e.g., "typography")
+    pub category: String,
+    /// Default severity: "error", "warning", or "suggestion"
+    pub severity: String,
+    /// Message shown with each result
+    pub message: String,
+    /// Longer explanation of what the check flags and why
+    pub explanation: String,
+    /// Style guide or tool the check comes from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Tags for filtering (includes "autofix" and "dialect" where they apply)
+    pub tags: Vec<String>,
+    /// Texts this check flags
+    pub bad_examples: Vec<String>,
+    /// Texts this check leaves alone
+    pub good_examples: Vec<String>,
+}
+
 /// A check that matches simple word/phrase existence
 pub struct ExistenceCheck {
     pub id: &'static str,
@@ -5,8 +5,28 @@
 //! - write-good
 //! - GNU diction
 
+use super::{keyed_examples, KeyedExamples};
 use crate::check::{Check, Severity};
 
+/// Examples for generated cliche checks, keyed by phrase
+const EXAMPLES: KeyedExamples = &[
+    (
+        "at the end of the day",
+        &["At the end of the day, the numbers decide."],
+        &["We left at the end of the workday."],
+    ),
+    (
+        "low-hanging fruit",
+        &["Start with the low-hanging fruit."],
+        &["The low branches were hanging with fruit."],
+    ),
+    (
+        "think outside the box",
+        &["We need to think outside the box."],
+        &["Store the cables outside the box."],
+    ),
+];
+
 /// Get all cliche checks
 pub fn get_checks() -> Vec<Check> {
     let mut checks = vec![
@@ -114,14 +134,16 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, phrase) in garner_cliches.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("cliches.garner.{}", i).into_boxed_str()),
                 Box::leak(format!("'{}' is a cliche.", phrase).into_boxed_str()),
                 phrase,
             )
             .with_severity(Severity::Suggestion),
-        );
+            phrase,
+            EXAMPLES,
+        ));
     }
 
     // write-good cliches (extensive list)
@@ -639,14 +661,16 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, phrase) in write_good_cliches.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("cliches.write_good.{}", i).into_boxed_str()),
                 Box::leak(format!("'{}' is a cliche.", phrase).into_boxed_str()),
                 phrase,
             )
             .with_severity(Severity::Suggestion),
-        );
+            phrase,
+            EXAMPLES,
+        ));
     }
 
     // GNU diction cliches
@@ -675,14 +699,16 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, phrase) in diction_cliches.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("cliches.diction.{}", i).into_boxed_str()),
                 Box::leak(format!("'{}' is a cliche.", phrase).into_boxed_str()),
                 phrase,
             )
             .with_severity(Severity::Suggestion),
-        );
+            phrase,
+            EXAMPLES,
+        ));
     }
 
     // Corporate/business cliches
@@ -720,14 +746,16 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, phrase) in corporate_cliches.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("cliches.corporate.{}", i).into_boxed_str()),
                 Box::leak(format!("'{}' is corporate jargon/cliche.", phrase).into_boxed_str()),
                 phrase,
             )
             .with_severity(Severity::Suggestion),
-        );
+            phrase,
+            EXAMPLES,
+        ));
     }
 
     checks
@@ -737,6 +765,11 @@ pub fn get_checks() -> Vec<Check> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_examples_attached() {
+        crate::checks::assert_examples_attached(&get_checks(), EXAMPLES);
+    }
+
     #[test]
     fn test_at_the_end_of_the_day() {
         let checks = get_checks();
@@ -0,0 +1,196 @@
+//! Dialect spelling checks for proselint-wasm
+//!
+//! Flags American or British spellings depending on the configured dialect.
+//! The British side of many pairs is already flagged by `needless_variants`
+//! and `preferred_forms`; this module supplies the American side for those
+//! pairs and adds both sides for common -ize/-ise and -or/-our words.
+
+use super::{keyed_examples, KeyedExamples};
+use crate::check::{Check, Severity};
+use crate::dialect::{preferring_dialects, Convention, Variant};
+
+/// Examples for generated checks, keyed by the flagged spelling
+const EXAMPLES: KeyedExamples = &[
+    // The American form is a prefix of the British one
+    (
+        "toward",
+        &["Walk toward the light."],
+        &["Walk towards the light."],
+    ),
+    (
+        "colourful",
+        &["A colourful banner hung outside."],
+        &["A colorful banner hung outside."],
+    ),
+];
+
+/// American forms whose British counterparts are flagged in other modules
+const AMERICAN_FORMS: &[(&str, &str, Convention)] = &[
+    // (US spelling, UK spelling, convention)
+    ("toward", "towards", Convention::Wards),
+    ("afterward", "afterwards", Convention::Wards),
+    ("epitomize", "epitomise", Convention::Ize),
+    ("globalize", "globalise", Convention::Ize),
+    ("homogenize", "homogenise", Convention::Ize),
+    ("hospitalize", "hospitalise", Convention::Ize),
+    ("humanize", "humanise", Convention::Ize),
+    ("hypnotize", "hypnotise", Convention::Ize),
+    ("hypothesize", "hypothesise", Convention::Ize),
+    ("idealize", "idealise", Convention::Ize),
+    ("immobilize", "immobilise", Convention::Ize),
+    ("immunize", "immunise", Convention::Ize),
+    ("individualize", "individualise", Convention::Ize),
+    ("industrialize", "industrialise", Convention::Ize),
+    ("initialize", "initialise", Convention::Ize),
+    ("institutionalize", "institutionalise", Convention::Ize),
+    ("internalize", "internalise", Convention::Ize),
+    ("internationalize", "internationalise", Convention::Ize),
+    ("itemize", "itemise", Convention::Ize),
+    ("jeopardize", "jeopardise", Convention::Ize),
+    ("legalize", "legalise", Convention::Ize),
+    ("legitimize", "legitimise", Convention::Ize),
+    ("liberalize", "liberalise", Convention::Ize),
+    ("localize", "localise", Convention::Ize),
+    ("magnetize", "magnetise", Convention::Ize),
+    ("marginalize", "marginalise", Convention::Ize),
+    ("materialize", "materialise", Convention::Ize),
+    ("maximize", "maximise", Convention::Ize),
+    ("mechanize", "mechanise", Convention::Ize),
+    ("memorize", "memorise", Convention::Ize),
+    ("mesmerize", "mesmerise", Convention::Ize),
+    ("metabolize", "metabolise", Convention::Ize),
+    ("militarize", "militarise", Convention::Ize),
+    ("minimize", "minimise", Convention::Ize),
+    ("mobilize", "mobilise", Convention::Ize),
+    ("modernize", "modernise", Convention::Ize),
+    ("moisturize", "moisturise", Convention::Ize),
+    ("monopolize", "monopolise", Convention::Ize),
+    ("moralize", "moralise", Convention::Ize),
+    ("nationalize", "nationalise", Convention::Ize),
+    ("naturalize", "naturalise", Convention::Ize),
+    ("neutralize", "neutralise", Convention::Ize),
+    ("normalize", "normalise", Convention::Ize),
+    ("favoritism", "favouritism", Convention::Our),
+    ("misbehavior", "misbehaviour", Convention::Our),
+    ("neighborhood", "neighbourhood", Convention::Our),
+    ("neighboring", "neighbouring", Convention::Our),
+    ("imperiled", "imperilled", Convention::DoubledL),
+    ("labeled", "labelled", Convention::DoubledL),
+    ("labeling", "labelling", Convention::DoubledL),
+    ("leveled", "levelled", Convention::DoubledL),
+    ("leveling", "levelling", Convention::DoubledL),
+    ("marveled", "marvelled", Convention::DoubledL),
+    ("marveling", "marvelling", Convention::DoubledL),
+    ("modeled", "modelled", Convention::DoubledL),
+    ("modeling", "modelling", Convention::DoubledL),
+    ("lodgment", "lodgement", Convention::Spelling),
+    ("draftsman", "draughtsman", Convention::Spelling),
+    ("maneuver", "manoeuvre", Convention::Spelling),
+    ("maneuverability", "manoeuvrability", Convention::Spelling),
+    ("millimeter", "millimetre", Convention::Re),
+];
+
+/// Variant pairs not covered elsewhere; both sides are flagged here
+const VARIANT_PAIRS: &[(&str, &str, Convention)] = &[
+    // (US spelling, UK spelling, convention)
+    ("apologize", "apologise", Convention::Ize),
+    ("authorize", "authorise", Convention::Ize),
+    ("categorize", "categorise", Convention::Ize),
+    ("characterize", "characterise", Convention::Ize),
+    ("criticize", "criticise", Convention::Ize),
+    ("emphasize", "emphasise", Convention::Ize),
+    ("finalize", "finalise", Convention::Ize),
+    ("optimize", "optimise", Convention::Ize),
+    ("prioritize", "prioritise", Convention::Ize),
+    ("specialize", "specialise", Convention::Ize),
+    ("summarize", "summarise", Convention::Ize),
+    ("paralyze", "paralyse", Convention::Ize),
+    ("organization", "organisation", Convention::Ize),
+    ("armor", "armour", Convention::Our),
+    ("endeavor", "endeavour", Convention::Our),
+    ("flavor", "flavour", Convention::Our),
+    ("harbor", "harbour", Convention::Our),
+    ("humor", "humour", Convention::Our),
+    ("rumor", "rumour", Convention::Our),
+    ("vapor", "vapour", Convention::Our),
+    ("favorite", "favourite", Convention::Our),
+    ("colorful", "colourful", Convention::Our),
+    ("fiber", "fibre", Convention::Re),
+    ("liter", "litre", Convention::Re),
+    ("somber", "sombre", Convention::Re),
+    ("caliber", "calibre", Convention::Re),
+    ("pretense", "pretence", Convention::Ence),
+    ("traveled", "travelled", Convention::DoubledL),
+    ("traveler", "traveller", Convention::DoubledL),
+];
+
+/// Build a check flagging one side of a variant pair
+fn variant_check(us: &str, uk: &str, convention: Convention, flagged: Variant) -> Check {
+    let (word, preferred) = match flagged {
+        Variant::American => (us, uk),
+        Variant::British => (uk, us),
+    };
+
+    let check = Check::new(
+        Box::leak(format!("dialects.{}.{}", convention.as_str(), word).into_boxed_str()),
+        Box::leak(
+            format!(
+                "'{}' is {} spelling. Use '{}' in {} English.",
+                word,
+                flagged.name(),
+                preferred,
+                preferring_dialects(convention, flagged.opposite())
+            )
+            .into_boxed_str(),
+        ),
+        Box::leak(word.to_string().into_boxed_str()),
+    )
+    .with_severity(Severity::Suggestion)
+    .dialect(convention, flagged)
+    .with_replacement(Box::leak(preferred.to_string().into_boxed_str()));
+    keyed_examples(check, word, EXAMPLES)
+}
+
+/// Get all dialect spelling checks
+pub fn get_checks() -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for &(us, uk, convention) in AMERICAN_FORMS {
+        checks.push(variant_check(us, uk, convention, Variant::American));
+    }
+
+    for &(us, uk, convention) in VARIANT_PAIRS {
+        checks.push(variant_check(us, uk, convention, Variant::American));
+        checks.push(variant_check(us, uk, convention, Variant::British));
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples_attached() {
+        crate::checks::assert_examples_attached(&get_checks(), EXAMPLES);
+    }
+
+    #[test]
+    fn test_variant_pair_both_sides() {
+        let checks = get_checks();
+        let us = checks
+            .iter()
+            .find(|c| c.id == "dialects.our.flavor")
+            .unwrap();
+        let uk = checks
+            .iter()
+            .find(|c| c.id == "dialects.our.flavour")
+            .unwrap();
+
+        assert_eq!(us.run("A subtle flavor.").len(), 1);
+        assert_eq!(uk.run("A subtle flavour.").len(), 1);
+        assert_eq!(us.dialect, Some((Convention::Our, Variant::American)));
+        assert_eq!(uk.replacement, Some("flavor"));
+    }
+}
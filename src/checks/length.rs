@@ -0,0 +1,151 @@
+//! Length checks for proselint-wasm
+//!
+//! Flags sentences and paragraphs that exceed the word or sentence limits
+//! set in `Config::length`. Markdown headings, list items and fenced code
+//! are skipped.
+
+use crate::check::{Check, CheckMatch, Severity};
+use crate::config::{Config, WindowUnit};
+use crate::tokenize::{prose_paragraphs, sentences, words};
+
+/// Get all length checks
+pub fn get_checks() -> Vec<Check> {
+    vec![
+        Check::with_matcher(
+            "length.sentence",
+            "This sentence is too long. Consider breaking it up.",
+            find_long_sentences,
+        )
+        .with_severity(Severity::Suggestion)
+        .explain(
+            "Long sentences are harder to follow. The limit is set in words per \
+             sentence through `Config::length`.",
+        )
+        .tags(&["readability"])
+        .allow_in_quotes(),
+        Check::with_matcher(
+            "length.paragraph",
+            "This paragraph is too long. Consider breaking it up.",
+            find_long_paragraphs,
+        )
+        .with_severity(Severity::Suggestion)
+        .explain(
+            "Long paragraphs form a wall of text. The limit is set in words or \
+             sentences per paragraph through `Config::length`.",
+        )
+        .tags(&["readability"])
+        .allow_in_quotes(),
+    ]
+}
+
+/// Flag each sentence with more words than `max_sentence_words`
+fn find_long_sentences(text: &str, config: &Config) -> Vec<CheckMatch> {
+    let limit = config.length.max_sentence_words;
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    let mut results = Vec::new();
+    for paragraph in prose_paragraphs(text) {
+        let offset = paragraph.start;
+        for sentence in sentences(&text[paragraph]) {
+            let (start, end) = (offset + sentence.start, offset + sentence.end);
+            let count = words(&text[start..end]).count();
+            if count > limit {
+                results.push(CheckMatch {
+                    message: Some(format!(
+                        "Sentence is {} words long (limit {}). Consider breaking it up.",
+                        count, limit
+                    )),
+                    ..CheckMatch::new(start, end)
+                });
+            }
+        }
+    }
+
+    results
+}
+
+/// Flag each paragraph longer than `max_paragraph_length` words or sentences
+fn find_long_paragraphs(text: &str, config: &Config) -> Vec<CheckMatch> {
+    let limit = config.length.max_paragraph_length;
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    let unit = config.length.paragraph_unit;
+    prose_paragraphs(text)
+        .into_iter()
+        .filter_map(|paragraph| {
+            let body = &text[paragraph.clone()];
+            let (count, name) = match unit {
+                WindowUnit::Words => (words(body).count(), "words"),
+                WindowUnit::Sentences => (sentences(body).len(), "sentences"),
+            };
+            (count > limit).then(|| CheckMatch {
+                message: Some(format!(
+                    "Paragraph is {} {} long (limit {}). Consider breaking it up.",
+                    count, name, limit
+                )),
+                ..CheckMatch::new(paragraph.start, paragraph.end)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LengthConfig;
+
+    fn config(length: LengthConfig) -> Config {
+        let mut config = Config::default();
+        config.length = length;
+        config
+    }
+
+    #[test]
+    fn test_long_sentence() {
+        let text = "Short one. This sentence has exactly eight words in it. Fine.";
+        let strict = config(LengthConfig {
+            max_sentence_words: 5,
+            ..Default::default()
+        });
+
+        let results = find_long_sentences(text, &strict);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            &text[results[0].start..results[0].end],
+            "This sentence has exactly eight words in it."
+        );
+        assert_eq!(
+            results[0].message.as_deref(),
+            Some("Sentence is 8 words long (limit 5). Consider breaking it up.")
+        );
+
+        assert!(find_long_sentences(text, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_long_paragraph_skips_markdown() {
+        let text = "# A heading that is long enough to count\n\n\
+                    - a list item with plenty of words in it\n\n\
+                    ```\nfn code() { with many words in the block }\n```\n\n\
+                    One. Two. Three.";
+        let strict = config(LengthConfig {
+            max_sentence_words: 3,
+            max_paragraph_length: 2,
+            paragraph_unit: WindowUnit::Sentences,
+        });
+
+        assert!(find_long_sentences(text, &strict).is_empty());
+        let results = find_long_paragraphs(text, &strict);
+        assert_eq!(results.len(), 1);
+        assert_eq!(&text[results[0].start..results[0].end], "One. Two. Three.");
+        assert!(results[0]
+            .message
+            .as_deref()
+            .unwrap()
+            .starts_with("Paragraph is 3 sentences long"));
+    }
+}
@@ -1,168 +1,120 @@
 //! Lexical illusion checks for proselint-wasm
 //!
-//! Detects unintentional word repetitions.
+//! Detects unintentional word repetitions ("the the"), including doubles
+//! split across a line break where they are easiest to miss.
 
-use crate::check::{Check, Severity};
+use crate::check::{Check, CheckMatch, Severity};
+use crate::config::Config;
+use crate::tokenize::{words, Word};
+
+/// Doubles that are usually intentional
+const LEGITIMATE_DOUBLES: &[&str] = &[
+    "had", "that", "bye", "ha", "no", "so", "knock", "tut", "tsk", "chop", "yada", "bora", "walla",
+    "cha", "can", "pom", "dum", "boo", "hush", "hubba", "tom", "mahi", "aye",
+];
+
+/// Words that introduce a copula construction ("What it is is ...", "All it is is ...")
+const COPULA_OPENERS: &[&str] = &["what", "all"];
 
 /// Get all lexical illusion checks
 pub fn get_checks() -> Vec<Check> {
-    vec![
-        // Common repeated words (not "had had" or "that that" which are valid)
-        Check::new(
-            "lexical_illusions.the_the",
-            "Repeated word 'the the' - likely a typo.",
-            r"the the",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("the"),
-        Check::new(
-            "lexical_illusions.a_a",
-            "Repeated word 'a a' - likely a typo.",
-            r"a a",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("a"),
-        Check::new(
-            "lexical_illusions.an_an",
-            "Repeated word 'an an' - likely a typo.",
-            r"an an",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("an"),
-        Check::new(
-            "lexical_illusions.is_is",
-            "Repeated word 'is is' - likely a typo.",
-            r"is is",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("is"),
-        Check::new(
-            "lexical_illusions.are_are",
-            "Repeated word 'are are' - likely a typo.",
-            r"are are",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("are"),
-        Check::new(
-            "lexical_illusions.was_was",
-            "Repeated word 'was was' - likely a typo.",
-            r"was was",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("was"),
-        Check::new(
-            "lexical_illusions.were_were",
-            "Repeated word 'were were' - likely a typo.",
-            r"were were",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("were"),
-        Check::new(
-            "lexical_illusions.to_to",
-            "Repeated word 'to to' - likely a typo.",
-            r"to to",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("to"),
-        Check::new(
-            "lexical_illusions.of_of",
-            "Repeated word 'of of' - likely a typo.",
-            r"of of",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("of"),
-        Check::new(
-            "lexical_illusions.and_and",
-            "Repeated word 'and and' - likely a typo.",
-            r"and and",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("and"),
-        Check::new(
-            "lexical_illusions.or_or",
-            "Repeated word 'or or' - likely a typo.",
-            r"or or",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("or"),
-        Check::new(
-            "lexical_illusions.in_in",
-            "Repeated word 'in in' - likely a typo.",
-            r"in in",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("in"),
-        Check::new(
-            "lexical_illusions.on_on",
-            "Repeated word 'on on' - likely a typo.",
-            r"on on",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("on"),
-        Check::new(
-            "lexical_illusions.it_it",
-            "Repeated word 'it it' - likely a typo.",
-            r"it it",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("it"),
-        Check::new(
-            "lexical_illusions.for_for",
-            "Repeated word 'for for' - likely a typo.",
-            r"for for",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("for"),
-        Check::new(
-            "lexical_illusions.be_be",
-            "Repeated word 'be be' - likely a typo.",
-            r"be be",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("be"),
-        Check::new(
-            "lexical_illusions.been_been",
-            "Repeated word 'been been' - likely a typo.",
-            r"been been",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("been"),
-        Check::new(
-            "lexical_illusions.have_have",
-            "Repeated word 'have have' - likely a typo.",
-            r"have have",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("have"),
-        Check::new(
-            "lexical_illusions.has_has",
-            "Repeated word 'has has' - likely a typo.",
-            r"has has",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("has"),
-        Check::new(
-            "lexical_illusions.will_will",
-            "Repeated word 'will will' - likely a typo.",
-            r"will will",
-        )
-        .with_severity(Severity::Error)
-        .with_replacement("will"),
-    ]
+    vec![Check::with_matcher(
+        "lexical_illusions.repeated_word",
+        "Repeated word - likely a typo.",
+        find_repeated_words,
+    )
+    .with_severity(Severity::Error)
+    // The fixed pairs ("to to", "the the") predate this check and still run
+    .supersedes(&["misc.tense"])
+    .explain(
+        "A word repeated across a line break or a short gap is easy to miss when \
+         proofreading, because the eye skips the duplicate.",
+    )
+    .tags(&["typo"])
+    .examples(&["I went to the the store."], &["She had had enough."])]
+}
+
+/// Returns true for doubles that are grammatical in context
+fn is_legitimate_double(tokens: &[Word], index: usize, word: &str) -> bool {
+    if LEGITIMATE_DOUBLES.contains(&word) || word.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+
+    // "is is" is fine after "what it" or "all it"
+    word == "is"
+        && tokens[index.saturating_sub(3)..index]
+            .iter()
+            .any(|w| COPULA_OPENERS.contains(&w.text.to_lowercase().as_str()))
+}
+
+/// Find immediately repeated words separated only by whitespace within a paragraph
+/// The replacement keeps the first occurrence and deletes the duplicate
+fn find_repeated_words(text: &str, _config: &Config) -> Vec<CheckMatch> {
+    let mut results = Vec::new();
+    let tokens: Vec<Word> = words(text).collect();
+    let mut last_flagged_end = 0;
+
+    for (i, pair) in tokens.windows(2).enumerate() {
+        let (first, second) = (pair[0], pair[1]);
+
+        // "the the the" is reported once
+        if first.start < last_flagged_end {
+            continue;
+        }
+
+        let word = first.text.to_lowercase();
+        let gap = &text[first.end..second.start];
+        // A blank line between them ends the paragraph (a sign-off and a signature)
+        if word != second.text.to_lowercase()
+            || !gap.chars().all(char::is_whitespace)
+            || gap.matches('\n').count() > 1
+            || is_legitimate_double(&tokens, i, &word)
+        {
+            continue;
+        }
+
+        results.push(CheckMatch {
+            replacement: Some(first.text.to_string()),
+            ..CheckMatch::new(first.start, second.end)
+        });
+        last_flagged_end = second.end;
+    }
+
+    results
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn repeated_word_check() -> Check {
+        get_checks()
+            .into_iter()
+            .find(|c| c.id == "lexical_illusions.repeated_word")
+            .unwrap()
+    }
+
     #[test]
     fn test_lexical_illusion() {
-        let checks = get_checks();
-        let check = checks
-            .iter()
-            .find(|c| c.id == "lexical_illusions.the_the")
-            .unwrap();
+        let check = repeated_word_check();
         let results = check.run("I went to the the store.");
-        assert_eq!(results.len(), 1);
+        assert_eq!(results, vec![(10, 17, Some("the".to_string()))]);
+
+        // Any word, any case, across a line break
+        assert_eq!(check.run("Send it to to me.").len(), 1);
+        assert_eq!(check.run("The\nthe end of of it").len(), 2);
+        // Not across a paragraph break
+        assert!(check.run("Best,\nDaniel\n\nDaniel Park\n").is_empty());
+        assert!(check.run("Best,\r\nDaniel\r\n\r\nDaniel").is_empty());
+    }
+
+    #[test]
+    fn test_legitimate_doubles() {
+        let check = repeated_word_check();
+        assert!(check.run("She had had enough.").is_empty());
+        assert!(check.run("He said that that was fine.").is_empty());
+        assert!(check.run("What it is is a mess.").is_empty());
+        assert!(check.run("Well, the, the point is this.").is_empty());
+        assert_eq!(check.run("The problem is is that.").len(), 1);
     }
 }
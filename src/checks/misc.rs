@@ -2,7 +2,8 @@
 //!
 //! Various writing quality checks that don't fit in other categories.
 
-use crate::check::{Check, Severity};
+use crate::check::{get_cached_regex, Check, CheckMatch, Severity};
+use crate::config::Config;
 
 /// Get all miscellaneous checks
 pub fn get_checks() -> Vec<Check> {
@@ -56,7 +57,8 @@ pub fn get_checks() -> Vec<Check> {
             r"alot",
         )
         .with_severity(Severity::Error)
-        .with_replacement("a lot"),
+        .with_replacement("a lot")
+        .examples(&["I like it alot."], &["I like it a lot."]),
         // "Could of" (should be "could have")
         Check::new(
             "misc.could_of",
@@ -64,7 +66,8 @@ pub fn get_checks() -> Vec<Check> {
             r"could of",
         )
         .with_severity(Severity::Error)
-        .with_replacement("could have"),
+        .with_replacement("could have")
+        .examples(&["I could of gone."], &["I could have gone."]),
         // "Would of"
         Check::new(
             "misc.would_of",
@@ -72,7 +75,8 @@ pub fn get_checks() -> Vec<Check> {
             r"would of",
         )
         .with_severity(Severity::Error)
-        .with_replacement("would have"),
+        .with_replacement("would have")
+        .examples(&["She would of known."], &["She would have known."]),
         // "Should of"
         Check::new(
             "misc.should_of",
@@ -80,7 +84,8 @@ pub fn get_checks() -> Vec<Check> {
             r"should of",
         )
         .with_severity(Severity::Error)
-        .with_replacement("should have"),
+        .with_replacement("should have")
+        .examples(&["You should of asked."], &["You should have asked."]),
         // "Its" vs "It's"
         Check::new(
             "misc.its_contraction",
@@ -109,23 +114,31 @@ pub fn get_checks() -> Vec<Check> {
             r"the affect|an affect",
         )
         .with_severity(Severity::Warning)
-        .with_replacement("the effect"),
+        .with_replacement("the effect")
+        .examples(
+            &["The affect was clear.", "It had an affect on me."],
+            &["The effect was clear."],
+        ),
         // Multiple exclamation marks
         Check::new(
             "misc.multiple_exclamation",
             "Multiple exclamation marks are generally unnecessary.",
             r"!{2,}",
         )
+        .raw()
         .with_severity(Severity::Warning)
-        .with_replacement("!"),
+        .with_replacement("!")
+        .examples(&["Stop!!"], &["Stop!"]),
         // Multiple question marks
         Check::new(
             "misc.multiple_question",
             "Multiple question marks are generally unnecessary.",
             r"\?{2,}",
         )
+        .raw()
         .with_severity(Severity::Warning)
-        .with_replacement("?"),
+        .with_replacement("?")
+        .examples(&["Really??"], &["Really?"]),
         // Run-on sentence indicator (very long sentence)
         Check::new(
             "misc.run_on_sentence",
@@ -140,14 +153,22 @@ pub fn get_checks() -> Vec<Check> {
             r"ceteris paribus",
         )
         .with_severity(Severity::Suggestion)
-        .with_replacement("other things being equal"),
+        .with_replacement("other things being equal")
+        .examples(
+            &["Ceteris paribus, prices rise."],
+            &["Other things being equal, prices rise."],
+        ),
         Check::new(
             "misc.latin.inter_alia",
             "Prefer among other things over the Latin inter alia.",
             r"inter alia",
         )
         .with_severity(Severity::Suggestion)
-        .with_replacement("among other things"),
+        .with_replacement("among other things")
+        .examples(
+            &["It covers, inter alia, taxes."],
+            &["It covers, among other things, taxes."],
+        ),
         Check::new(
             "misc.latin.simpliciter",
             "Prefer simply or in itself over the Latin simpliciter.",
@@ -181,14 +202,16 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("therefore"),
+        .with_replacement("therefore")
+        .examples(&["Ergo, we left."], &["Ergonomic chairs help."]),
         Check::new(
             "misc.latin.qua",
             "Consider whether qua could be replaced with as or in the capacity of.",
             r"\bqua\b",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["Art qua art."], &["A quantity of art."]),
         Check::new(
             "misc.latin.viz",
             "Consider using namely instead of viz.",
@@ -229,7 +252,8 @@ pub fn get_checks() -> Vec<Check> {
             r"heretofore",
         )
         .with_severity(Severity::Warning)
-        .with_replacement("previously"),
+        .with_replacement("previously")
+        .examples(&["As heretofore noted."], &["As noted before."]),
         Check::new(
             "misc.pretension.hitherto",
             "hitherto is pretentious. Use until now or previously.",
@@ -286,7 +310,11 @@ pub fn get_checks() -> Vec<Check> {
             r"enthuse(?:d|s)?",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(
+            &["She enthused about it."],
+            &["She was enthusiastic about it."],
+        ),
         Check::new(
             "misc.back_formations.liaise",
             "liaise is a back-formation. Consider work with or coordinate with.",
@@ -309,7 +337,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Error)
-        .with_replacement("phenomenon"),
+        .with_replacement("phenomenon")
+        .examples(&["This phenomena is rare."], &["This phenomenon is rare."]),
         Check::new(
             "misc.false_plurals.criteria",
             "criteria is plural. Use criterion for singular.",
@@ -317,7 +346,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Error)
-        .with_replacement("criterion"),
+        .with_replacement("criterion")
+        .examples(&["One criteria matters."], &["One criterion matters."]),
         Check::new(
             "misc.false_plurals.data",
             "data is technically plural, though singular usage is now accepted.",
@@ -338,7 +368,11 @@ pub fn get_checks() -> Vec<Check> {
             r"(?:an?|one) alumni\b",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(
+            &["He is an alumni of Yale."],
+            &["He is an alumnus of Yale."],
+        ),
         // Inferior/superior usage
         Check::new(
             "misc.inferior_superior.more_superior",
@@ -346,7 +380,8 @@ pub fn get_checks() -> Vec<Check> {
             r"more superior",
         )
         .with_severity(Severity::Error)
-        .with_replacement("superior"),
+        .with_replacement("superior")
+        .examples(&["It is more superior."], &["It is superior."]),
         Check::new(
             "misc.inferior_superior.more_inferior",
             "more inferior is redundant. Use just inferior.",
@@ -367,7 +402,8 @@ pub fn get_checks() -> Vec<Check> {
             r"\$[\d,.]+ dollars",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["It cost $5 dollars."], &["It cost $5."]),
         Check::new(
             "misc.currency.redundant_pounds",
             "Do not use both pound sign and pounds.",
@@ -381,7 +417,8 @@ pub fn get_checks() -> Vec<Check> {
             r"€[\d,.]+ euros",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["It cost €20 euros."], &["It cost €20."]),
         // Scare quotes overuse
         Check::new(
             "misc.scare_quotes.so_called",
@@ -397,14 +434,20 @@ pub fn get_checks() -> Vec<Check> {
             r"close proximity",
         )
         .with_severity(Severity::Warning)
-        .with_replacement("proximity"),
+        .supersedes(&["redundancy"])
+        .with_replacement("proximity")
+        .examples(
+            &["They sat in close proximity."],
+            &["They sat close together."],
+        ),
         Check::new(
             "misc.illogic.exact_same",
             "exact same is redundant. Use same or exactly the same.",
             r"exact same",
         )
         .with_severity(Severity::Suggestion)
-        .with_replacement("same"),
+        .with_replacement("same")
+        .examples(&["The exact same dress."], &["The same dress."]),
         Check::new(
             "misc.illogic.reason_why",
             "reason why can often be just reason or why.",
@@ -412,13 +455,16 @@ pub fn get_checks() -> Vec<Check> {
         )
         .with_severity(Severity::Suggestion),
         // Professions
-        Check::new(
+        Check::with_matcher(
             "misc.professions.attorney",
             "attorney in American usage should be attorney at law formally, or just lawyer.",
-            r"\battorney(?!s? at law|\s+general)",
+            find_bare_attorney,
         )
-        .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(
+            &["Call your attorney."],
+            &["An attorney at law.", "The attorney general spoke."],
+        ),
         // Waxed/grew
         Check::new(
             "misc.waxed.waxed",
@@ -434,7 +480,11 @@ pub fn get_checks() -> Vec<Check> {
             r"from whence",
         )
         .with_severity(Severity::Error)
-        .with_replacement("whence"),
+        .with_replacement("whence")
+        .examples(
+            &["Return from whence you came."],
+            &["Return whence you came."],
+        ),
         // Many a
         Check::new(
             "misc.many_a.singular",
@@ -450,31 +500,38 @@ pub fn get_checks() -> Vec<Check> {
             r"\bsuddenly\b",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["Suddenly, the door opened."], &["The door opened."]),
         // Capitalization
         Check::new(
             "misc.capitalization.internet",
             "Internet is now typically lowercase: internet.",
-            r"\bInternet\b",
+            r"\b(?-i:Internet)\b",
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("internet"),
+        .with_replacement("internet")
+        .examples(&["Search the Internet."], &["Search the internet."]),
         Check::new(
             "misc.capitalization.web",
             "Web (meaning World Wide Web) is now typically lowercase: web.",
-            r"\bthe Web\b",
+            r"\bthe (?-i:Web)\b",
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("the web"),
+        .with_replacement("the web")
+        .examples(&["I found it on the Web."], &["I found it on the web."]),
         // ===== Strunk & White composition rules (omit needless words) =====
         Check::new(
             "misc.composition.the_fact_that",
             "'the fact that' is wordy. Often can be omitted entirely.",
             r"the fact that",
         )
-        .with_severity(Severity::Warning),
+        .with_severity(Severity::Warning)
+        .examples(
+            &["Despite the fact that it rained."],
+            &["Although it rained."],
+        ),
         Check::new(
             "misc.composition.who_is",
             "'who is' / 'which was' constructions can often be shortened.",
@@ -543,7 +600,11 @@ pub fn get_checks() -> Vec<Check> {
             r"owing to the fact that",
         )
         .with_severity(Severity::Warning)
-        .with_replacement("because"),
+        .with_replacement("because")
+        .examples(
+            &["Owing to the fact that it rained."],
+            &["Because it rained."],
+        ),
         Check::new(
             "misc.composition.in_spite_of",
             "'in spite of the fact that' is wordy. Use 'although' or 'though'.",
@@ -620,7 +681,8 @@ pub fn get_checks() -> Vec<Check> {
             r"in order to",
         )
         .with_severity(Severity::Suggestion)
-        .with_replacement("to"),
+        .with_replacement("to")
+        .examples(&["We left in order to rest."], &["We left to rest."]),
         Check::new(
             "misc.composition.in_the_event_that",
             "'in the event that' is wordy. Use 'if'.",
@@ -642,7 +704,8 @@ pub fn get_checks() -> Vec<Check> {
             r"at this point in time",
         )
         .with_severity(Severity::Warning)
-        .with_replacement("now"),
+        .with_replacement("now")
+        .examples(&["At this point in time, we wait."], &["Now, we wait."]),
         Check::new(
             "misc.debased.at_this_moment_in_time",
             "'at this moment in time' is bloated. Use 'now'.",
@@ -655,7 +718,11 @@ pub fn get_checks() -> Vec<Check> {
             "'going forward' is corporate jargon. Use 'in the future' or omit.",
             r"going forward",
         )
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(
+            &["Going forward, we ship weekly."],
+            &["From now on, we ship weekly."],
+        ),
         Check::new(
             "misc.debased.paradigm_shift",
             "'paradigm shift' is overused jargon. Consider a simpler alternative.",
@@ -677,7 +744,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("use"),
+        .with_replacement("use")
+        .examples(&["We utilize tools."], &["We use tools."]),
         // ===== Institution names =====
         Check::new(
             "misc.institution_name.virginia_tech",
@@ -708,7 +776,8 @@ pub fn get_checks() -> Vec<Check> {
             r"(?:found|declared|pronounced)\s+innocent",
         )
         .raw()
-        .with_severity(Severity::Warning),
+        .with_severity(Severity::Warning)
+        .examples(&["He was found innocent."], &["He was found not guilty."]),
         // ===== Tense consistency (tense_present.py patterns) =====
         Check::new(
             "misc.tense.had_had",
@@ -737,48 +806,77 @@ pub fn get_checks() -> Vec<Check> {
             r"\bthe the\b",
         )
         .raw()
-        .with_severity(Severity::Error),
-        Check::new("misc.tense.a_a", "'a a' is likely an error.", r"\ba a\b")
-            .raw()
-            .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["Go to the the store."], &["Go to the store."]),
+        // Case-sensitive so "Plan A a day" and "Type A, a ..." aren't flagged
+        Check::new(
+            "misc.tense.a_a",
+            "'a a' is likely an error.",
+            r"\b(?-i:a a)\b",
+        )
+        .raw()
+        .with_severity(Severity::Error)
+        .examples(&["It is a a mistake."], &["Take vitamin A a day."]),
         Check::new(
             "misc.tense.an_an",
             "'an an' is likely an error.",
             r"\ban an\b",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["It was an an apple."], &["It was an apple."]),
         Check::new(
             "misc.tense.and_and",
             "'and and' is likely an error.",
             r"\band and\b",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["Salt and and pepper."], &["Salt and pepper."]),
         Check::new(
             "misc.tense.of_of",
             "'of of' is likely an error.",
             r"\bof of\b",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["One of of them."], &["One of them."]),
         Check::new(
             "misc.tense.to_to",
             "'to to' is likely an error.",
             r"\bto to\b",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["Go to to bed."], &["Go to bed."]),
         Check::new(
             "misc.tense.for_for",
             "'for for' is likely an error.",
             r"\bfor for\b",
         )
         .raw()
-        .with_severity(Severity::Error),
+        .with_severity(Severity::Error)
+        .examples(&["Wait for for me."], &["Wait for me."]),
     ]
 }
 
+/// Find "attorney" not followed by "at law" or "general"
+/// (the regex crate has no lookahead, so the exclusion is checked by hand)
+fn find_bare_attorney(text: &str, _config: &Config) -> Vec<CheckMatch> {
+    let (Some(attorney), Some(exempt)) = (
+        get_cached_regex(r"(?i)\battorney"),
+        get_cached_regex(r"(?i)^(?:s? at law|\s+general)"),
+    ) else {
+        return Vec::new();
+    };
+
+    attorney
+        .find_iter(text)
+        .filter(|m| !exempt.is_match(&text[m.end()..]))
+        .map(|m| CheckMatch::new(m.start(), m.end()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,43 +2,365 @@
 //!
 //! Registers and exposes all available checks.
 
+#[cfg(feature = "annotations")]
 pub mod annotations;
+#[cfg(feature = "archaism")]
 pub mod archaism;
+#[cfg(feature = "cliches")]
 pub mod cliches;
+#[cfg(feature = "dates_times")]
 pub mod dates_times;
+#[cfg(feature = "dialects")]
+pub mod dialects;
+#[cfg(feature = "hedging")]
 pub mod hedging;
+#[cfg(feature = "industrial_language")]
 pub mod industrial_language;
+#[cfg(feature = "length")]
+pub mod length;
+#[cfg(feature = "lexical_illusions")]
 pub mod lexical_illusions;
+#[cfg(feature = "malapropisms")]
 pub mod malapropisms;
+#[cfg(feature = "misc")]
 pub mod misc;
+#[cfg(feature = "mixed_metaphors")]
 pub mod mixed_metaphors;
+#[cfg(feature = "mondegreens")]
 pub mod mondegreens;
+#[cfg(feature = "needless_variants")]
 pub mod needless_variants;
+#[cfg(feature = "nonwords")]
 pub mod nonwords;
+#[cfg(feature = "oxymorons")]
 pub mod oxymorons;
+#[cfg(feature = "preferred_forms")]
 pub mod preferred_forms;
+#[cfg(feature = "psychology")]
 pub mod psychology;
+#[cfg(feature = "redundancy")]
 pub mod redundancy;
+#[cfg(feature = "repetition")]
+pub mod repetition;
+#[cfg(feature = "restricted")]
 pub mod restricted;
+#[cfg(feature = "skunked_terms")]
 pub mod skunked_terms;
+#[cfg(feature = "social_awareness")]
 pub mod social_awareness;
+#[cfg(feature = "spelling")]
 pub mod spelling;
+#[cfg(feature = "terms")]
 pub mod terms;
+#[cfg(feature = "typography")]
 pub mod typography;
+#[cfg(feature = "uncomparables")]
 pub mod uncomparables;
+#[cfg(feature = "weasel_words")]
 pub mod weasel_words;
 
-use crate::check::Check;
+use crate::check::{Check, CheckInfo};
+use crate::config::Config;
+use std::collections::HashMap;
 
 // Cache the checks vector so we don't recreate it on every lint call
-static ALL_CHECKS: once_cell::sync::Lazy<Vec<Check>> =
-    once_cell::sync::Lazy::new(build_all_checks);
+static ALL_CHECKS: once_cell::sync::Lazy<Vec<Check>> = once_cell::sync::Lazy::new(build_all_checks);
 
 /// Get all registered checks (cached)
 pub fn get_all_checks() -> &'static [Check] {
     &ALL_CHECKS
 }
 
+// Index from check ID to position in ALL_CHECKS
+static CHECK_INDEX: once_cell::sync::Lazy<HashMap<&'static str, usize>> =
+    once_cell::sync::Lazy::new(|| {
+        get_all_checks()
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.id, i))
+            .collect()
+    });
+
+/// Look up a registered check by ID
+pub fn get_check(id: &str) -> Option<&'static Check> {
+    CHECK_INDEX.get(id).map(|&i| &get_all_checks()[i])
+}
+
+/// Hand-picked (key, bad, good) examples for checks generated from a list
+/// The key is the phrase or word the check is generated from
+#[cfg(any(
+    feature = "cliches",
+    feature = "dialects",
+    feature = "redundancy",
+    feature = "uncomparables"
+))]
+pub(crate) type KeyedExamples = &'static [(
+    &'static str,
+    &'static [&'static str],
+    &'static [&'static str],
+)];
+
+/// Attach the examples listed for `key`, if there are any
+#[cfg(any(
+    feature = "cliches",
+    feature = "dialects",
+    feature = "redundancy",
+    feature = "uncomparables"
+))]
+pub(crate) fn keyed_examples(check: Check, key: &str, table: KeyedExamples) -> Check {
+    match table.iter().find(|(k, _, _)| *k == key) {
+        Some(&(_, bad, good)) => check.examples(bad, good),
+        None => check,
+    }
+}
+
+/// Assert that every key in `table` matched one of `checks`
+#[cfg(all(
+    test,
+    any(
+        feature = "cliches",
+        feature = "dialects",
+        feature = "redundancy",
+        feature = "uncomparables"
+    )
+))]
+pub(crate) fn assert_examples_attached(checks: &[Check], table: KeyedExamples) {
+    for (key, bad, _) in table {
+        assert!(
+            checks.iter().any(|c| c.bad_examples == *bad),
+            "no check generated from {:?}",
+            key
+        );
+    }
+}
+
+/// One-line description of each category, used as the default check explanation
+const CATEGORIES: &[(&str, &str)] = &[
+    (
+        "annotations",
+        "Leftover editorial notes and placeholders such as TODO or lorem ipsum.",
+    ),
+    (
+        "archaism",
+        "Archaic words and phrases that read as stilted in modern prose.",
+    ),
+    ("cliches", "Overused phrases that have lost their force."),
+    (
+        "dates_times",
+        "Incorrect or redundant date and time expressions.",
+    ),
+    (
+        "dialects",
+        "Spellings that don't match the configured English dialect.",
+    ),
+    ("hedging", "Qualifiers that weaken an assertion."),
+    ("industrial_language", "Corporate jargon and buzzwords."),
+    (
+        "length",
+        "Sentences and paragraphs longer than the configured limits.",
+    ),
+    (
+        "lexical_illusions",
+        "Accidentally repeated words that the eye tends to skip.",
+    ),
+    (
+        "malapropisms",
+        "A similar-sounding word used in place of the intended one.",
+    ),
+    (
+        "misc",
+        "Assorted usage problems that don't fit another category.",
+    ),
+    (
+        "mixed_metaphors",
+        "Metaphors that are garbled or combine incompatible images.",
+    ),
+    ("mondegreens", "Misheard versions of common phrases."),
+    (
+        "needless_variants",
+        "Variant word forms where a standard form exists.",
+    ),
+    ("nonwords", "Words that aren't standard English."),
+    ("oxymorons", "Phrases whose parts contradict each other."),
+    (
+        "preferred_forms",
+        "Forms preferred by usage guides, including hyphenated phrasal adjectives.",
+    ),
+    ("psychology", "Clinical terms used casually or imprecisely."),
+    ("redundancy", "Phrases that say the same thing twice."),
+    (
+        "repetition",
+        "Words repeated within a short span or overused across a document.",
+    ),
+    (
+        "restricted",
+        "Informal words and abbreviations unsuited to polished prose.",
+    ),
+    (
+        "skunked_terms",
+        "Words in disputed transition, which distract some readers whichever way they are used.",
+    ),
+    (
+        "social_awareness",
+        "Outdated or exclusionary terms with neutral alternatives.",
+    ),
+    (
+        "spelling",
+        "Common misspellings and commonly confused words.",
+    ),
+    (
+        "terms",
+        "More precise terms, such as animal adjectives and collective nouns.",
+    ),
+    (
+        "typography",
+        "Typographic conventions for symbols, dashes, quotes and spacing.",
+    ),
+    (
+        "uncomparables",
+        "Absolute adjectives, such as unique, used with a comparison.",
+    ),
+    (
+        "weasel_words",
+        "Vague intensifiers and qualifiers that undermine a claim.",
+    ),
+];
+
+/// Default sources, matched by the longest ID prefix
+const SOURCES: &[(&str, &str)] = &[
+    ("cliches.diction", "GNU diction"),
+    ("cliches.garner", "Garner's Modern American Usage"),
+    ("cliches.write_good", "write-good"),
+    ("needless_variants", "Garner's Modern American Usage"),
+    ("redundancy.atd", "After the Deadline"),
+    ("redundancy.garner", "Garner's Modern American Usage"),
+    ("skunked_terms", "Garner's Modern American Usage"),
+    ("typography", "Butterick's Practical Typography"),
+];
+
+/// Get the description of a check category
+pub fn category_description(category: &str) -> Option<&'static str> {
+    CATEGORIES
+        .iter()
+        .find(|(name, _)| *name == category)
+        .map(|(_, description)| *description)
+}
+
+/// Categories with at least one check compiled into this build
+/// Each category is a cargo feature of the same name, all enabled by default
+pub fn compiled_categories() -> Vec<&'static str> {
+    CATEGORIES
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| get_all_checks().iter().any(|c| c.category() == *name))
+        .collect()
+}
+
+/// Build the metadata for a check, filling gaps from its category
+fn check_info(check: &Check) -> CheckInfo {
+    let to_strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+
+    let source = check.source.or_else(|| {
+        SOURCES
+            .iter()
+            .filter(|(prefix, _)| {
+                check.id == *prefix
+                    || check.id.starts_with(prefix) && check.id[prefix.len()..].starts_with('.')
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, source)| *source)
+    });
+
+    let mut tags: Vec<String> = to_strings(check.tags);
+    if check.replacement.is_some() {
+        tags.push("autofix".to_string());
+    }
+    if check.dialect.is_some() {
+        tags.push("dialect".to_string());
+    }
+
+    CheckInfo {
+        id: check.id.to_string(),
+        category: check.category().to_string(),
+        severity: check.severity.as_str().to_string(),
+        message: check.message.to_string(),
+        explanation: check
+            .explanation
+            .or_else(|| category_description(check.category()))
+            .unwrap_or(check.message)
+            .to_string(),
+        source: source.map(str::to_string),
+        tags,
+        bad_examples: to_strings(check.bad_examples),
+        good_examples: to_strings(check.good_examples),
+    }
+}
+
+/// Describe a check by ID
+pub fn describe_check(id: &str) -> Option<CheckInfo> {
+    get_check(id).map(check_info)
+}
+
+/// Describe every registered check, in registry order
+pub fn check_catalog() -> Vec<CheckInfo> {
+    get_all_checks().iter().map(check_info).collect()
+}
+
+/// How many registered checks carry positive/negative examples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExampleCoverage {
+    /// Checks with at least one bad or good example
+    pub with_examples: usize,
+    /// All registered checks
+    pub total: usize,
+}
+
+impl ExampleCoverage {
+    /// Share of checks with examples (0.0 to 1.0)
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.with_examples as f64 / self.total as f64
+        }
+    }
+}
+
+/// Report the share of checks that carry examples
+pub fn example_coverage() -> ExampleCoverage {
+    let checks = get_all_checks();
+    ExampleCoverage {
+        with_examples: checks
+            .iter()
+            .filter(|c| !c.bad_examples.is_empty() || !c.good_examples.is_empty())
+            .count(),
+        total: checks.len(),
+    }
+}
+
+/// Run every check against its own examples
+/// Returns a description of each bad example that isn't flagged and each good
+/// example that is, or an empty vector if all examples hold
+pub fn verify_examples() -> Vec<String> {
+    let config = Config::default();
+    let mut failures = Vec::new();
+
+    for check in get_all_checks() {
+        for example in check.bad_examples {
+            if check.find_matches(example, &config).is_empty() {
+                failures.push(format!("{}: should flag {:?}", check.id, example));
+            }
+        }
+        for example in check.good_examples {
+            if !check.find_matches(example, &config).is_empty() {
+                failures.push(format!("{}: should not flag {:?}", check.id, example));
+            }
+        }
+    }
+
+    failures
+}
+
 /// Validate all registered checks
 /// Returns a list of validation errors, or empty vector if all checks are valid
 pub fn validate_all_checks() -> Vec<String> {
@@ -55,83 +377,122 @@ pub fn validate_all_checks() -> Vec<String> {
 
 /// Build all checks (called once during initialization)
 fn build_all_checks() -> Vec<Check> {
+    // Not mutated when every category is compiled out
+    #[allow(unused_mut)]
     let mut checks = Vec::new();
 
     // Typography checks
+    #[cfg(feature = "typography")]
     checks.extend(typography::get_checks());
 
     // Weasel words
+    #[cfg(feature = "weasel_words")]
     checks.extend(weasel_words::get_checks());
 
     // Hedging
+    #[cfg(feature = "hedging")]
     checks.extend(hedging::get_checks());
 
     // Redundancy
+    #[cfg(feature = "redundancy")]
     checks.extend(redundancy::get_checks());
 
     // Cliches
+    #[cfg(feature = "cliches")]
     checks.extend(cliches::get_checks());
 
     // Miscellaneous
+    #[cfg(feature = "misc")]
     checks.extend(misc::get_checks());
 
     // Archaisms
+    #[cfg(feature = "archaism")]
     checks.extend(archaism::get_checks());
 
     // Annotations
+    #[cfg(feature = "annotations")]
     checks.extend(annotations::get_checks());
 
     // Dates and times
+    #[cfg(feature = "dates_times")]
     checks.extend(dates_times::get_checks());
 
     // Industrial/corporate language
+    #[cfg(feature = "industrial_language")]
     checks.extend(industrial_language::get_checks());
 
     // Lexical illusions (repeated words)
+    #[cfg(feature = "lexical_illusions")]
     checks.extend(lexical_illusions::get_checks());
 
+    // Sentence and paragraph length
+    #[cfg(feature = "length")]
+    checks.extend(length::get_checks());
+
+    // Repetition (echo words and crutch words)
+    #[cfg(feature = "repetition")]
+    checks.extend(repetition::get_checks());
+
     // Malapropisms
+    #[cfg(feature = "malapropisms")]
     checks.extend(malapropisms::get_checks());
 
     // Mixed metaphors
+    #[cfg(feature = "mixed_metaphors")]
     checks.extend(mixed_metaphors::get_checks());
 
     // Mondegreens (misheard phrases)
+    #[cfg(feature = "mondegreens")]
     checks.extend(mondegreens::get_checks());
 
     // Needless variants
+    #[cfg(feature = "needless_variants")]
     checks.extend(needless_variants::get_checks());
 
     // Nonwords
+    #[cfg(feature = "nonwords")]
     checks.extend(nonwords::get_checks());
 
     // Oxymorons
+    #[cfg(feature = "oxymorons")]
     checks.extend(oxymorons::get_checks());
 
     // Psychology (clinical term misuse)
+    #[cfg(feature = "psychology")]
     checks.extend(psychology::get_checks());
 
     // Restricted vocabulary
+    #[cfg(feature = "restricted")]
     checks.extend(restricted::get_checks());
 
     // Skunked terms
+    #[cfg(feature = "skunked_terms")]
     checks.extend(skunked_terms::get_checks());
 
     // Social awareness
+    #[cfg(feature = "social_awareness")]
     checks.extend(social_awareness::get_checks());
 
     // Spelling/word confusion
+    #[cfg(feature = "spelling")]
     checks.extend(spelling::get_checks());
 
     // Terms (animal adjectives, denizens, venery)
+    #[cfg(feature = "terms")]
     checks.extend(terms::get_checks());
 
     // Uncomparables
+    #[cfg(feature = "uncomparables")]
     checks.extend(uncomparables::get_checks());
 
     // Preferred forms and phrasal adjectives
+    #[cfg(feature = "preferred_forms")]
     checks.extend(preferred_forms::get_checks());
 
+    // Dialect spelling variants
+    #[cfg(feature = "dialects")]
+    checks.extend(dialects::get_checks());
+
     checks
 }
 
@@ -152,12 +513,68 @@ pub fn get_checks_by_category(category: &str) -> Vec<&'static Check> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "all-checks")]
     #[test]
     fn test_get_all_checks() {
         let checks = get_all_checks();
         assert!(!checks.is_empty());
     }
 
+    #[test]
+    fn test_all_patterns_compile() {
+        let errors = validate_all_checks();
+        assert!(errors.is_empty(), "{}", errors.join("\n"));
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let failures = verify_examples();
+        let coverage = example_coverage();
+        println!(
+            "Example coverage: {}/{} checks ({:.1}%)",
+            coverage.with_examples,
+            coverage.total,
+            coverage.ratio() * 100.0
+        );
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[cfg(feature = "all-checks")]
+    #[test]
+    fn test_all_categories_compiled() {
+        assert_eq!(compiled_categories().len(), CATEGORIES.len());
+    }
+
+    #[test]
+    fn test_every_category_described() {
+        for check in get_all_checks() {
+            assert!(
+                category_description(check.category()).is_some(),
+                "No description for category of '{}'",
+                check.id
+            );
+        }
+    }
+
+    #[cfg(feature = "all-checks")]
+    #[test]
+    fn test_describe_check() {
+        let info = describe_check("typography.symbols.ellipsis").unwrap();
+        assert_eq!(info.category, "typography");
+        assert_eq!(info.severity, "suggestion");
+        assert_eq!(
+            info.source.as_deref(),
+            Some("Butterick's Practical Typography")
+        );
+        assert!(info.tags.contains(&"autofix".to_string()));
+
+        let info = describe_check("redundancy.atd.30").unwrap();
+        assert_eq!(info.source.as_deref(), Some("After the Deadline"));
+        assert!(describe_check("no.such.check").is_none());
+        assert_eq!(check_catalog().len(), get_all_checks().len());
+    }
+
+    #[cfg(feature = "typography")]
     #[test]
     fn test_get_typography_checks() {
         let checks = get_checks_by_category("typography");
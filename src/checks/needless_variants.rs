@@ -3,6 +3,7 @@
 //! Detects unnecessary variant spellings and forms.
 
 use crate::check::{Check, Severity};
+use crate::dialect::{Convention, Variant};
 
 /// Get all needless variant checks
 pub fn get_checks() -> Vec<Check> {
@@ -15,6 +16,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("toward"),
         Check::new(
             "needless_variants.afterwards",
@@ -23,6 +26,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("afterward"),
         Check::new(
             "needless_variants.backwards",
@@ -31,6 +36,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("backward"),
         Check::new(
             "needless_variants.forwards",
@@ -39,6 +46,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("forward"),
         Check::new(
             "needless_variants.upwards",
@@ -47,6 +56,7 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("upward"),
         Check::new(
             "needless_variants.downwards",
@@ -55,6 +65,7 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("downward"),
         Check::new(
             "needless_variants.inwards",
@@ -63,6 +74,7 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("inward"),
         Check::new(
             "needless_variants.outwards",
@@ -71,6 +83,7 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Wards, Variant::British)
         .with_replacement("outward"),
         // Needless variant forms
         Check::new(
@@ -86,6 +99,8 @@ pub fn get_checks() -> Vec<Check> {
             r"amongst",
         )
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
+        .dialect(Convention::Among, Variant::British)
         .with_replacement("among"),
         Check::new(
             "needless_variants.whilst",
@@ -93,6 +108,8 @@ pub fn get_checks() -> Vec<Check> {
             r"whilst",
         )
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
+        .dialect(Convention::Among, Variant::British)
         .with_replacement("while"),
         Check::new(
             "needless_variants.unbeknownst",
@@ -127,6 +144,7 @@ pub fn get_checks() -> Vec<Check> {
             r"firstly",
         )
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
         .with_replacement("first"),
         Check::new(
             "needless_variants.secondly",
@@ -134,6 +152,7 @@ pub fn get_checks() -> Vec<Check> {
             r"secondly",
         )
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
         .with_replacement("second"),
         Check::new(
             "needless_variants.thirdly",
@@ -141,6 +160,7 @@ pub fn get_checks() -> Vec<Check> {
             r"thirdly",
         )
         .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"])
         .with_replacement("third"),
         Check::new(
             "needless_variants.lastly",
@@ -183,6 +203,7 @@ pub fn get_checks() -> Vec<Check> {
             r"anyways",
         )
         .with_severity(Severity::Warning)
+        .supersedes(&["preferred_forms.usage"])
         .with_replacement("anyway"),
         Check::new(
             "needless_variants.irregardless",
@@ -190,6 +211,7 @@ pub fn get_checks() -> Vec<Check> {
             r"irregardless",
         )
         .with_severity(Severity::Error)
+        .supersedes(&["preferred_forms.usage"])
         .with_replacement("regardless"),
         Check::new(
             "needless_variants.oftentimes",
@@ -204,7 +226,8 @@ pub fn get_checks() -> Vec<Check> {
             r"utiliz(?:e|ed|es|ing|ation)",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .supersedes(&["preferred_forms.usage"]),
         Check::new(
             "needless_variants.methodology",
             "'methodology' is often unnecessarily formal. Consider 'method'.",
@@ -653,6 +676,7 @@ pub fn get_checks() -> Vec<Check> {
             r"draughtsman",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("drafter"),
         Check::new(
             "needless_variants.effortful",
@@ -715,6 +739,7 @@ pub fn get_checks() -> Vec<Check> {
             r"epitomise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("epitomize"),
         Check::new(
             "needless_variants.equableness",
@@ -839,6 +864,7 @@ pub fn get_checks() -> Vec<Check> {
             r"favouritism",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Our, Variant::British)
         .with_replacement("favoritism"),
         Check::new(
             "needless_variants.fermentative",
@@ -949,6 +975,7 @@ pub fn get_checks() -> Vec<Check> {
             r"glamourise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Our, Variant::British)
         .with_replacement("glamorize"),
         Check::new(
             "needless_variants.globalise",
@@ -956,6 +983,7 @@ pub fn get_checks() -> Vec<Check> {
             r"globalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("globalize"),
         Check::new(
             "needless_variants.governorate",
@@ -1049,6 +1077,7 @@ pub fn get_checks() -> Vec<Check> {
             r"homogenise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("homogenize"),
         Check::new(
             "needless_variants.hospitalise",
@@ -1056,6 +1085,7 @@ pub fn get_checks() -> Vec<Check> {
             r"hospitalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("hospitalize"),
         Check::new(
             "needless_variants.humanise",
@@ -1063,6 +1093,7 @@ pub fn get_checks() -> Vec<Check> {
             r"humanise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("humanize"),
         Check::new(
             "needless_variants.hypnotise",
@@ -1070,6 +1101,7 @@ pub fn get_checks() -> Vec<Check> {
             r"hypnotise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("hypnotize"),
         Check::new(
             "needless_variants.hypothesise",
@@ -1077,6 +1109,7 @@ pub fn get_checks() -> Vec<Check> {
             r"hypothesise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("hypothesize"),
         Check::new(
             "needless_variants.idealise",
@@ -1084,6 +1117,7 @@ pub fn get_checks() -> Vec<Check> {
             r"idealise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("idealize"),
         Check::new(
             "needless_variants.illegalize",
@@ -1097,6 +1131,7 @@ pub fn get_checks() -> Vec<Check> {
             r"immobilise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("immobilize"),
         Check::new(
             "needless_variants.immunise",
@@ -1104,6 +1139,7 @@ pub fn get_checks() -> Vec<Check> {
             r"immunise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("immunize"),
         Check::new(
             "needless_variants.impactful",
@@ -1117,6 +1153,7 @@ pub fn get_checks() -> Vec<Check> {
             r"imperilled",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("imperiled"),
         Check::new(
             "needless_variants.impermissable",
@@ -1131,6 +1168,7 @@ pub fn get_checks() -> Vec<Check> {
             r"impersonalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("impersonalize"),
         Check::new(
             "needless_variants.importunement",
@@ -1169,6 +1207,7 @@ pub fn get_checks() -> Vec<Check> {
             r"individualise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("individualize"),
         Check::new(
             "needless_variants.industrialise",
@@ -1176,6 +1215,7 @@ pub fn get_checks() -> Vec<Check> {
             r"industrialise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("industrialize"),
         Check::new(
             "needless_variants.inescapeable",
@@ -1202,6 +1242,7 @@ pub fn get_checks() -> Vec<Check> {
             r"initialise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("initialize"),
         Check::new(
             "needless_variants.initiatory",
@@ -1229,6 +1270,7 @@ pub fn get_checks() -> Vec<Check> {
             r"institutionalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("institutionalize"),
         Check::new(
             "needless_variants.instrumentalise",
@@ -1236,6 +1278,7 @@ pub fn get_checks() -> Vec<Check> {
             r"instrumentalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("instrumentalize"),
         Check::new(
             "needless_variants.intellectualise",
@@ -1243,6 +1286,7 @@ pub fn get_checks() -> Vec<Check> {
             r"intellectualise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("intellectualize"),
         Check::new(
             "needless_variants.intenseness",
@@ -1264,6 +1308,7 @@ pub fn get_checks() -> Vec<Check> {
             r"internalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("internalize"),
         Check::new(
             "needless_variants.internationalise",
@@ -1271,6 +1316,7 @@ pub fn get_checks() -> Vec<Check> {
             r"internationalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("internationalize"),
         Check::new(
             "needless_variants.interpretative",
@@ -1297,6 +1343,7 @@ pub fn get_checks() -> Vec<Check> {
             r"ionise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("ionize"),
         Check::new(
             "needless_variants.irreconcileable",
@@ -1311,6 +1358,7 @@ pub fn get_checks() -> Vec<Check> {
             r"itemise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("itemize"),
         Check::new(
             "needless_variants.jeopardise",
@@ -1318,6 +1366,7 @@ pub fn get_checks() -> Vec<Check> {
             r"jeopardise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("jeopardize"),
         Check::new(
             "needless_variants.judgement",
@@ -1325,6 +1374,7 @@ pub fn get_checks() -> Vec<Check> {
             r"judgement",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("judgment"),
         Check::new(
             "needless_variants.knowledgable",
@@ -1339,6 +1389,7 @@ pub fn get_checks() -> Vec<Check> {
             r"labelled",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("labeled"),
         Check::new(
             "needless_variants.labelling",
@@ -1346,6 +1397,7 @@ pub fn get_checks() -> Vec<Check> {
             r"labelling",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("labeling"),
         Check::new(
             "needless_variants.legalise",
@@ -1353,6 +1405,7 @@ pub fn get_checks() -> Vec<Check> {
             r"legalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("legalize"),
         Check::new(
             "needless_variants.legitimatise",
@@ -1360,6 +1413,7 @@ pub fn get_checks() -> Vec<Check> {
             r"legitimatise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("legitimize"),
         Check::new(
             "needless_variants.legitimise",
@@ -1367,6 +1421,7 @@ pub fn get_checks() -> Vec<Check> {
             r"legitimise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("legitimize"),
         Check::new(
             "needless_variants.levelled",
@@ -1374,6 +1429,7 @@ pub fn get_checks() -> Vec<Check> {
             r"levelled",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("leveled"),
         Check::new(
             "needless_variants.levelling",
@@ -1381,6 +1437,7 @@ pub fn get_checks() -> Vec<Check> {
             r"levelling",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("leveling"),
         Check::new(
             "needless_variants.liberalise",
@@ -1388,6 +1445,7 @@ pub fn get_checks() -> Vec<Check> {
             r"liberalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("liberalize"),
         Check::new(
             "needless_variants.licence",
@@ -1395,6 +1453,7 @@ pub fn get_checks() -> Vec<Check> {
             r"licence",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ence, Variant::British)
         .with_replacement("license"),
         Check::new(
             "needless_variants.likeable",
@@ -1408,6 +1467,7 @@ pub fn get_checks() -> Vec<Check> {
             r"localise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("localize"),
         Check::new(
             "needless_variants.lodgement",
@@ -1415,6 +1475,7 @@ pub fn get_checks() -> Vec<Check> {
             r"lodgement",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("lodgment"),
         Check::new(
             "needless_variants.magnetise",
@@ -1422,6 +1483,7 @@ pub fn get_checks() -> Vec<Check> {
             r"magnetise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("magnetize"),
         Check::new(
             "needless_variants.manoeuvrability",
@@ -1429,6 +1491,7 @@ pub fn get_checks() -> Vec<Check> {
             r"manoeuvrability",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("maneuverability"),
         Check::new(
             "needless_variants.manoeuvre",
@@ -1436,6 +1499,7 @@ pub fn get_checks() -> Vec<Check> {
             r"manoeuvre",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("maneuver"),
         Check::new(
             "needless_variants.marginalise",
@@ -1443,6 +1507,7 @@ pub fn get_checks() -> Vec<Check> {
             r"marginalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("marginalize"),
         Check::new(
             "needless_variants.marvelled",
@@ -1450,6 +1515,7 @@ pub fn get_checks() -> Vec<Check> {
             r"marvelled",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("marveled"),
         Check::new(
             "needless_variants.marvelling",
@@ -1457,6 +1523,7 @@ pub fn get_checks() -> Vec<Check> {
             r"marvelling",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("marveling"),
         Check::new(
             "needless_variants.materialise",
@@ -1464,6 +1531,7 @@ pub fn get_checks() -> Vec<Check> {
             r"materialise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("materialize"),
         Check::new(
             "needless_variants.maximise",
@@ -1471,6 +1539,7 @@ pub fn get_checks() -> Vec<Check> {
             r"maximise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("maximize"),
         Check::new(
             "needless_variants.mechanise",
@@ -1478,6 +1547,7 @@ pub fn get_checks() -> Vec<Check> {
             r"mechanise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("mechanize"),
         Check::new(
             "needless_variants.mediaeval",
@@ -1485,6 +1555,7 @@ pub fn get_checks() -> Vec<Check> {
             r"mediaeval",
         )
         .with_severity(Severity::Warning)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("medieval"),
         Check::new(
             "needless_variants.memorialise",
@@ -1492,6 +1563,7 @@ pub fn get_checks() -> Vec<Check> {
             r"memorialise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("memorialize"),
         Check::new(
             "needless_variants.memorise",
@@ -1499,6 +1571,7 @@ pub fn get_checks() -> Vec<Check> {
             r"memorise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("memorize"),
         Check::new(
             "needless_variants.mesmerise",
@@ -1506,6 +1579,7 @@ pub fn get_checks() -> Vec<Check> {
             r"mesmerise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("mesmerize"),
         Check::new(
             "needless_variants.metabolise",
@@ -1513,6 +1587,7 @@ pub fn get_checks() -> Vec<Check> {
             r"metabolise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("metabolize"),
         Check::new(
             "needless_variants.militarise",
@@ -1520,6 +1595,7 @@ pub fn get_checks() -> Vec<Check> {
             r"militarise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("militarize"),
         Check::new(
             "needless_variants.millimetre",
@@ -1527,6 +1603,7 @@ pub fn get_checks() -> Vec<Check> {
             r"millimetre",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Re, Variant::British)
         .with_replacement("millimeter"),
         Check::new(
             "needless_variants.minimise",
@@ -1534,6 +1611,7 @@ pub fn get_checks() -> Vec<Check> {
             r"minimise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("minimize"),
         Check::new(
             "needless_variants.misbehaviour",
@@ -1541,6 +1619,7 @@ pub fn get_checks() -> Vec<Check> {
             r"misbehaviour",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Our, Variant::British)
         .with_replacement("misbehavior"),
         Check::new(
             "needless_variants.misspelt",
@@ -1548,6 +1627,7 @@ pub fn get_checks() -> Vec<Check> {
             r"misspelt",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::PastTense, Variant::British)
         .with_replacement("misspelled"),
         Check::new(
             "needless_variants.mobilise",
@@ -1555,6 +1635,7 @@ pub fn get_checks() -> Vec<Check> {
             r"mobilise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("mobilize"),
         Check::new(
             "needless_variants.modelled",
@@ -1562,6 +1643,7 @@ pub fn get_checks() -> Vec<Check> {
             r"modelled",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("modeled"),
         Check::new(
             "needless_variants.modelling",
@@ -1569,6 +1651,7 @@ pub fn get_checks() -> Vec<Check> {
             r"modelling",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::DoubledL, Variant::British)
         .with_replacement("modeling"),
         Check::new(
             "needless_variants.modernise",
@@ -1576,6 +1659,7 @@ pub fn get_checks() -> Vec<Check> {
             r"modernise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("modernize"),
         Check::new(
             "needless_variants.moisturise",
@@ -1583,6 +1667,7 @@ pub fn get_checks() -> Vec<Check> {
             r"moisturise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("moisturize"),
         Check::new(
             "needless_variants.monopolise",
@@ -1590,6 +1675,7 @@ pub fn get_checks() -> Vec<Check> {
             r"monopolise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("monopolize"),
         Check::new(
             "needless_variants.moralise",
@@ -1597,6 +1683,7 @@ pub fn get_checks() -> Vec<Check> {
             r"moralise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("moralize"),
         Check::new(
             "needless_variants.motorise",
@@ -1604,6 +1691,7 @@ pub fn get_checks() -> Vec<Check> {
             r"motorise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("motorize"),
         Check::new(
             "needless_variants.nationalise",
@@ -1611,6 +1699,7 @@ pub fn get_checks() -> Vec<Check> {
             r"nationalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("nationalize"),
         Check::new(
             "needless_variants.naturalise",
@@ -1618,6 +1707,7 @@ pub fn get_checks() -> Vec<Check> {
             r"naturalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("naturalize"),
         Check::new(
             "needless_variants.neighbour",
@@ -1625,6 +1715,7 @@ pub fn get_checks() -> Vec<Check> {
             r"neighbour",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Our, Variant::British)
         .with_replacement("neighbor"),
         Check::new(
             "needless_variants.neighbourhood",
@@ -1632,6 +1723,7 @@ pub fn get_checks() -> Vec<Check> {
             r"neighbourhood",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Our, Variant::British)
         .with_replacement("neighborhood"),
         Check::new(
             "needless_variants.neighbouring",
@@ -1639,6 +1731,7 @@ pub fn get_checks() -> Vec<Check> {
             r"neighbouring",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Our, Variant::British)
         .with_replacement("neighboring"),
         Check::new(
             "needless_variants.neutralise",
@@ -1646,6 +1739,7 @@ pub fn get_checks() -> Vec<Check> {
             r"neutralise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("neutralize"),
         Check::new(
             "needless_variants.normalise",
@@ -1653,6 +1747,7 @@ pub fn get_checks() -> Vec<Check> {
             r"normalise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("normalize"),
         Check::new(
             "needless_variants.notarise",
@@ -1660,6 +1755,7 @@ pub fn get_checks() -> Vec<Check> {
             r"notarise",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Ize, Variant::British)
         .with_replacement("notarize"),
     ]
 }
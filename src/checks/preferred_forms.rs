@@ -3,6 +3,7 @@
 //! Suggests preferred word forms and proper hyphenation for phrasal adjectives.
 
 use crate::check::{Check, Severity};
+use crate::dialect::{Convention, Variant};
 
 /// Get all preferred forms checks
 pub fn get_checks() -> Vec<Check> {
@@ -348,6 +349,17 @@ pub fn get_checks() -> Vec<Check> {
         )
         .with_severity(Severity::Suggestion);
 
+        // British forms are only flagged when the dialect prefers the American side
+        let convention = match *pattern {
+            "amongst" | "whilst" => Some(Convention::Among),
+            "learnt" | "burnt" | "dreamt" | "spelt" | "leapt" => Some(Convention::PastTense),
+            "towards" | "forwards" | "backwards" | "afterwards" => Some(Convention::Wards),
+            _ => None,
+        };
+        if let Some(convention) = convention {
+            check = check.dialect(convention, Variant::British);
+        }
+
         if !replacement.is_empty() {
             check = check.with_replacement(replacement);
         }
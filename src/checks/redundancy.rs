@@ -4,8 +4,33 @@
 //! - After the Deadline
 //! - Garner's Modern American Usage
 
+use super::{keyed_examples, KeyedExamples};
 use crate::check::{Check, Severity};
 
+/// Examples for generated redundancy checks, keyed by pattern
+const EXAMPLES: KeyedExamples = &[
+    (
+        "close proximity",
+        &["They stood in close proximity."],
+        &["They stood close to the door."],
+    ),
+    (
+        "the reason is because",
+        &["The reason is because we ran late."],
+        &["The reason is that we ran late."],
+    ),
+    (
+        "whether or not",
+        &["Decide whether or not to go."],
+        &["Decide whether to go or not."],
+    ),
+    (
+        "write down",
+        &["Write down the number."],
+        &["The write-down cut profits."],
+    ),
+];
+
 /// Get all redundancy checks
 pub fn get_checks() -> Vec<Check> {
     let mut checks = Vec::new();
@@ -280,7 +305,7 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, (pattern, replacement)) in atd_redundancies.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("redundancy.atd.{}", i).into_boxed_str()),
                 Box::leak(format!("Redundancy. Try '{}'.", replacement).into_boxed_str()),
@@ -288,7 +313,9 @@ pub fn get_checks() -> Vec<Check> {
             )
             .with_severity(Severity::Warning)
             .with_replacement(replacement),
-        );
+            pattern,
+            EXAMPLES,
+        ));
     }
 
     // Garner's redundancies
@@ -350,7 +377,7 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, (pattern, replacement)) in garner_redundancies.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("redundancy.garner.{}", i).into_boxed_str()),
                 Box::leak(format!("Redundancy. Try '{}'.", replacement).into_boxed_str()),
@@ -358,7 +385,9 @@ pub fn get_checks() -> Vec<Check> {
             )
             .with_severity(Severity::Warning)
             .with_replacement(replacement),
-        );
+            pattern,
+            EXAMPLES,
+        ));
     }
 
     // RAS Syndrome (Redundant Acronym Syndrome)
@@ -381,7 +410,7 @@ pub fn get_checks() -> Vec<Check> {
     ];
 
     for (i, (pattern, replacement)) in ras_patterns.iter().enumerate() {
-        checks.push(
+        checks.push(keyed_examples(
             Check::new(
                 Box::leak(format!("redundancy.ras.{}", i).into_boxed_str()),
                 Box::leak(format!("RAS syndrome. Try '{}'.", replacement).into_boxed_str()),
@@ -389,7 +418,9 @@ pub fn get_checks() -> Vec<Check> {
             )
             .with_severity(Severity::Warning)
             .with_replacement(replacement),
-        );
+            pattern,
+            EXAMPLES,
+        ));
     }
 
     checks
@@ -399,6 +430,11 @@ pub fn get_checks() -> Vec<Check> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_examples_attached() {
+        crate::checks::assert_examples_attached(&get_checks(), EXAMPLES);
+    }
+
     #[test]
     fn test_close_proximity() {
         let checks = get_checks();
@@ -0,0 +1,284 @@
+//! Repetition checks for proselint-wasm
+//!
+//! Flags content words that echo within a short span ("echo words") and
+//! words that are overused across the whole document ("crutch words").
+//! Words are compared by a rough lemma, so "decide", "decided" and
+//! "deciding" count as the same word.
+
+use crate::check::{Check, CheckMatch, Severity};
+use crate::config::{Config, WindowUnit};
+use crate::tokenize::{lemma, sentences, words};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Function words and very common verbs that are never counted
+const STOPWORDS: &[&str] = &[
+    "about", "above", "after", "again", "against", "also", "although", "another", "because",
+    "been", "before", "being", "below", "between", "both", "could", "does", "doing", "down",
+    "during", "each", "even", "every", "from", "further", "had", "have", "having", "here", "into",
+    "just", "like", "made", "make", "many", "more", "most", "much", "must", "never", "only",
+    "other", "over", "said", "same", "says", "should", "some", "still", "such", "than", "that",
+    "their", "them", "then", "there", "these", "they", "this", "those", "through", "under",
+    "until", "upon", "very", "was", "were", "what", "when", "where", "whether", "which", "while",
+    "will", "with", "within", "without", "would", "your", "yours",
+];
+
+/// Words shorter than this are never counted
+const MIN_WORD_LEN: usize = 4;
+
+/// Get all repetition checks
+pub fn get_checks() -> Vec<Check> {
+    vec![
+        Check::with_matcher(
+            "repetition.echo_word",
+            "Word repeated within a short span.",
+            find_echo_words,
+        )
+        .with_severity(Severity::Suggestion)
+        .examples(
+            &["The budget was tight. We trimmed the budget. Budgets matter."],
+            &["The budget was tight. We trimmed costs. Spending matters."],
+        )
+        .explain(
+            "The same uncommon word used several times in a short span draws the \
+             reader's attention. The window and threshold are set in `Config::repetition`.",
+        )
+        .tags(&["readability"])
+        .document_wide(),
+        Check::with_matcher(
+            "repetition.crutch_word",
+            "Word overused across the document.",
+            find_crutch_words,
+        )
+        .with_severity(Severity::Suggestion)
+        .explain(
+            "A word that makes up an unusual share of a document is often a verbal \
+             habit rather than a deliberate choice.",
+        )
+        .tags(&["readability"])
+        .document_wide(),
+    ]
+}
+
+/// A word used unusually often across a document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrutchWord {
+    /// The word as first written (lowercased)
+    pub word: String,
+    /// Number of occurrences, counting all inflections
+    pub count: usize,
+    /// Share of the document's content words (0.02 = 2%)
+    pub frequency: f64,
+    /// Byte spans of every occurrence
+    pub occurrences: Vec<Range<usize>>,
+}
+
+/// A counted content word
+struct Occurrence<'a> {
+    text: &'a str,
+    lemma: String,
+    span: Range<usize>,
+    /// Index of the word or sentence, depending on the window unit
+    position: usize,
+}
+
+/// Collect the content words of a text, positioned in the given unit
+fn content_words(text: &str, unit: WindowUnit) -> Vec<Occurrence<'_>> {
+    let sentence_spans = match unit {
+        WindowUnit::Sentences => sentences(text),
+        WindowUnit::Words => Vec::new(),
+    };
+    let mut sentence = 0;
+
+    let mut occurrences = Vec::new();
+    for (index, word) in words(text).enumerate() {
+        let position = match unit {
+            WindowUnit::Words => index,
+            WindowUnit::Sentences => {
+                while sentence_spans
+                    .get(sentence)
+                    .is_some_and(|span| span.end <= word.start)
+                {
+                    sentence += 1;
+                }
+                sentence
+            }
+        };
+
+        let lower = word.text.to_lowercase();
+        if lower.chars().count() < MIN_WORD_LEN
+            || lower.chars().any(|c| c.is_ascii_digit())
+            || STOPWORDS.contains(&lower.as_str())
+        {
+            continue;
+        }
+
+        occurrences.push(Occurrence {
+            text: word.text,
+            lemma: lemma(&lower),
+            span: word.start..word.end,
+            position,
+        });
+    }
+
+    occurrences
+}
+
+/// Group occurrence indices by lemma, keeping document order within each group
+fn group_by_lemma<'a>(occurrences: &'a [Occurrence]) -> HashMap<&'a str, Vec<usize>> {
+    let mut groups: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, occurrence) in occurrences.iter().enumerate() {
+        groups.entry(occurrence.lemma.as_str()).or_default().push(i);
+    }
+    groups
+}
+
+/// Flag every occurrence that brings a word's count within the window to the threshold
+/// Each result links the other occurrences in its window as related spans
+fn find_echo_words(text: &str, config: &Config) -> Vec<CheckMatch> {
+    let settings = &config.repetition;
+    if settings.window == 0 || settings.threshold < 2 {
+        return Vec::new();
+    }
+
+    let unit = match settings.unit {
+        WindowUnit::Words => "words",
+        WindowUnit::Sentences => "sentences",
+    };
+
+    let occurrences = content_words(text, settings.unit);
+    let mut results = Vec::new();
+
+    for group in group_by_lemma(&occurrences).values() {
+        for (k, &i) in group.iter().enumerate() {
+            let current = &occurrences[i];
+            let first = group[..k].partition_point(|&j| {
+                current.position - occurrences[j].position >= settings.window
+            });
+            let in_window = &group[first..=k];
+            if in_window.len() < settings.threshold {
+                continue;
+            }
+
+            results.push(CheckMatch {
+                message: Some(format!(
+                    "'{}' is used {} times within {} {}. Consider a synonym or rephrasing.",
+                    current.text.to_lowercase(),
+                    in_window.len(),
+                    settings.window,
+                    unit
+                )),
+                related: in_window
+                    .iter()
+                    .filter(|&&j| j != i)
+                    .map(|&j| occurrences[j].span.clone())
+                    .collect(),
+                ..CheckMatch::new(current.span.start, current.span.end)
+            });
+        }
+    }
+
+    results.sort_by_key(|m| m.start);
+    results
+}
+
+/// Report each crutch word once, at its first occurrence
+fn find_crutch_words(text: &str, config: &Config) -> Vec<CheckMatch> {
+    crutch_words(text, config)
+        .into_iter()
+        .map(|crutch| {
+            let first = crutch.occurrences[0].clone();
+            CheckMatch {
+                message: Some(format!(
+                    "'{}' appears {} times ({:.1}% of content words). It may be a crutch word.",
+                    crutch.word,
+                    crutch.count,
+                    crutch.frequency * 100.0
+                )),
+                related: crutch.occurrences[1..].to_vec(),
+                ..CheckMatch::new(first.start, first.end)
+            }
+        })
+        .collect()
+}
+
+/// Find words used unusually often across a whole document
+/// A word qualifies when it reaches both `crutch_min_count` occurrences and
+/// `crutch_min_frequency` of all content words; the most frequent come first
+pub fn crutch_words(text: &str, config: &Config) -> Vec<CrutchWord> {
+    let settings = &config.repetition;
+    let occurrences = content_words(text, WindowUnit::Words);
+    if occurrences.is_empty() {
+        return Vec::new();
+    }
+
+    let total = occurrences.len() as f64;
+    let mut report: Vec<CrutchWord> = group_by_lemma(&occurrences)
+        .into_values()
+        .filter(|group| {
+            group.len() >= settings.crutch_min_count.max(2)
+                && group.len() as f64 / total >= settings.crutch_min_frequency
+        })
+        .map(|group| CrutchWord {
+            word: occurrences[group[0]].text.to_lowercase(),
+            count: group.len(),
+            frequency: group.len() as f64 / total,
+            occurrences: group.iter().map(|&i| occurrences[i].span.clone()).collect(),
+        })
+        .collect();
+
+    report.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_echo_word_window() {
+        let text = "The budget was tight. We trimmed the budget twice. Budgets matter.";
+        let results = find_echo_words(text, &Config::default());
+
+        // Only the third occurrence crosses the threshold; it links the other two
+        assert_eq!(results.len(), 1);
+        assert_eq!(&text[results[0].start..results[0].end], "Budgets");
+        assert_eq!(results[0].related, vec![4..10, 37..43]);
+
+        let mut config = Config::default();
+        config.repetition.window = 5;
+        assert!(find_echo_words(text, &config).is_empty());
+
+        config.repetition.unit = WindowUnit::Sentences;
+        config.repetition.window = 3;
+        assert_eq!(find_echo_words(text, &config).len(), 1);
+        config.repetition.window = 2;
+        assert!(find_echo_words(text, &config).is_empty());
+    }
+
+    #[test]
+    fn test_stopwords_ignored() {
+        let text = "This is what they said, what they said, and what they said again.";
+        assert!(find_echo_words(text, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_crutch_words() {
+        let mut config = Config::default();
+        config.repetition.crutch_min_count = 3;
+        config.repetition.crutch_min_frequency = 0.2;
+
+        let text = "Basically the plan works. Basically the team agrees. \
+                    The budget basically holds, and the launch follows.";
+        let report = crutch_words(text, &config);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].word, "basically");
+        assert_eq!(report[0].count, 3);
+        assert_eq!(report[0].occurrences.len(), 3);
+
+        let results = find_crutch_words(text, &config);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].related.len(), 2);
+    }
+}
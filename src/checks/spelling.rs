@@ -12,6 +12,7 @@
 //! - misc common misspellings
 
 use crate::check::{Check, Severity};
+use crate::dialect::{preferring_dialects, Convention, Variant};
 
 /// Get all spelling checks
 pub fn get_checks() -> Vec<Check> {
@@ -30,6 +31,7 @@ pub fn get_checks() -> Vec<Check> {
             r"the affect",
         )
         .with_severity(Severity::Warning)
+        .supersedes(&["misc.affect_effect"])
         .with_replacement("the effect"),
         Check::new(
             "spelling.allude_elude",
@@ -466,6 +468,7 @@ pub fn get_checks() -> Vec<Check> {
             r"judgement",
         )
         .with_severity(Severity::Suggestion)
+        .dialect(Convention::Spelling, Variant::British)
         .with_replacement("judgment"),
         Check::new(
             "spelling.knowlege",
@@ -1691,73 +1694,77 @@ pub fn get_checks() -> Vec<Check> {
         );
     }
 
-    // US/UK spelling consistency checks - flags mixing of American and British spellings
-    let us_uk_pairs: &[(&str, &str, &str)] = &[
-        // (US spelling, UK spelling, word root)
-        ("color", "colour", "color/colour"),
-        ("favor", "favour", "favor/favour"),
-        ("honor", "honour", "honor/honour"),
-        ("labor", "labour", "labor/labour"),
-        ("neighbor", "neighbour", "neighbor/neighbour"),
-        ("behavior", "behaviour", "behavior/behaviour"),
-        ("organize", "organise", "organize/organise"),
-        ("realize", "realise", "realize/realise"),
-        ("recognize", "recognise", "recognize/recognise"),
-        ("analyze", "analyse", "analyze/analyse"),
-        ("center", "centre", "center/centre"),
-        ("theater", "theatre", "theater/theatre"),
-        ("defense", "defence", "defense/defence"),
-        ("offense", "offence", "offense/offence"),
-        ("license", "licence", "license/licence"),
-        ("practice", "practise", "practice/practise"),
-        ("catalog", "catalogue", "catalog/catalogue"),
-        ("dialog", "dialogue", "dialog/dialogue"),
-        ("traveling", "travelling", "traveling/travelling"),
-        ("canceled", "cancelled", "canceled/cancelled"),
-        ("jewelry", "jewellery", "jewelry/jewellery"),
-        ("gray", "grey", "gray/grey"),
-        ("aging", "ageing", "aging/ageing"),
-        ("judgment", "judgement", "judgment/judgement"),
-        (
-            "acknowledgment",
-            "acknowledgement",
-            "acknowledgment/acknowledgement",
-        ),
-        ("fulfill", "fulfil", "fulfill/fulfil"),
-        ("skillful", "skilful", "skillful/skilful"),
-        ("enrollment", "enrolment", "enrollment/enrolment"),
+    // US/UK spelling pairs - which side is flagged depends on the configured dialect
+    let us_uk_pairs: &[(&str, &str, Convention)] = &[
+        // (US spelling, UK spelling, convention)
+        ("color", "colour", Convention::Our),
+        ("favor", "favour", Convention::Our),
+        ("honor", "honour", Convention::Our),
+        ("labor", "labour", Convention::Our),
+        ("neighbor", "neighbour", Convention::Our),
+        ("behavior", "behaviour", Convention::Our),
+        ("organize", "organise", Convention::Ize),
+        ("realize", "realise", Convention::Ize),
+        ("recognize", "recognise", Convention::Ize),
+        ("analyze", "analyse", Convention::Ize),
+        ("center", "centre", Convention::Re),
+        ("theater", "theatre", Convention::Re),
+        ("defense", "defence", Convention::Ence),
+        ("offense", "offence", Convention::Ence),
+        ("license", "licence", Convention::Ence),
+        ("practice", "practise", Convention::Ence),
+        ("catalog", "catalogue", Convention::Spelling),
+        ("dialog", "dialogue", Convention::Spelling),
+        ("traveling", "travelling", Convention::DoubledL),
+        ("canceled", "cancelled", Convention::DoubledL),
+        ("jewelry", "jewellery", Convention::Spelling),
+        ("gray", "grey", Convention::Spelling),
+        ("aging", "ageing", Convention::Spelling),
+        ("judgment", "judgement", Convention::Spelling),
+        ("acknowledgment", "acknowledgement", Convention::Spelling),
+        ("fulfill", "fulfil", Convention::DoubledL),
+        ("skillful", "skilful", Convention::DoubledL),
+        ("enrollment", "enrolment", Convention::DoubledL),
     ];
 
-    for (i, (us, uk, _root)) in us_uk_pairs.iter().enumerate() {
-        // Add check for US spelling
+    for (i, &(us, uk, convention)) in us_uk_pairs.iter().enumerate() {
+        // Flags the US spelling when the dialect prefers the UK one
         checks.push(
             Check::new(
                 Box::leak(format!("spelling.consistency.us.{}", i).into_boxed_str()),
                 Box::leak(
                     format!(
-                        "'{}' is American English. Ensure consistent spelling style.",
-                        us
+                        "'{}' is American spelling. Use '{}' in {} English.",
+                        us,
+                        uk,
+                        preferring_dialects(convention, Variant::British)
                     )
                     .into_boxed_str(),
                 ),
                 us,
             )
-            .with_severity(Severity::Suggestion),
+            .with_severity(Severity::Suggestion)
+            .dialect(convention, Variant::American)
+            .with_replacement(uk),
         );
-        // Add check for UK spelling
+        // Flags the UK spelling when the dialect prefers the US one
         checks.push(
             Check::new(
                 Box::leak(format!("spelling.consistency.uk.{}", i).into_boxed_str()),
                 Box::leak(
                     format!(
-                        "'{}' is British English. Ensure consistent spelling style.",
-                        uk
+                        "'{}' is British spelling. Use '{}' in {} English.",
+                        uk,
+                        us,
+                        preferring_dialects(convention, Variant::American)
                     )
                     .into_boxed_str(),
                 ),
                 uk,
             )
-            .with_severity(Severity::Suggestion),
+            .with_severity(Severity::Suggestion)
+            .dialect(convention, Variant::British)
+            .with_replacement(us),
         );
     }
 
@@ -3,7 +3,10 @@
 //! Based on Butterick's Practical Typography recommendations.
 //! Includes diacritical mark patterns from proselint.
 
-use crate::check::{Check, Severity};
+use crate::check::{Check, CheckMatch, Severity};
+use crate::config::Config;
+use crate::dialect::{Convention, Variant};
+use crate::position::QuoteTracker;
 
 /// Get all typography checks
 pub fn get_checks() -> Vec<Check> {
@@ -16,7 +19,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{2026}"),
+        .with_replacement("\u{2026}")
+        .examples(&["Wait..."], &["Wait\u{2026}"]),
         // Copyright symbol
         Check::new(
             "typography.symbols.copyright",
@@ -25,7 +29,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{00A9}"),
+        .with_replacement("\u{00A9}")
+        .examples(&["Copyright (c) 2024 Acme."], &["Copyright © 2024 Acme."]),
         // Trademark symbol
         Check::new(
             "typography.symbols.trademark",
@@ -34,7 +39,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{2122}"),
+        .with_replacement("\u{2122}")
+        .examples(&["Acme(tm) widgets."], &["Acme™ widgets."]),
         // Registered trademark
         Check::new(
             "typography.symbols.registered",
@@ -43,7 +49,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{00AE}"),
+        .with_replacement("\u{00AE}")
+        .examples(&["Acme(r) widgets."], &["Acme® widgets."]),
         // Multiple spaces after period (sentence spacing)
         Check::new(
             "typography.symbols.sentence_spacing",
@@ -51,7 +58,11 @@ pub fn get_checks() -> Vec<Check> {
             r"\. {3,}",
         )
         .raw()
-        .with_severity(Severity::Warning),
+        .with_severity(Severity::Warning)
+        .examples(
+            &["It ended.   Then it began."],
+            &["It ended. Then it began."],
+        ),
         // Multiplication symbol
         Check::new(
             "typography.symbols.multiplication",
@@ -59,16 +70,20 @@ pub fn get_checks() -> Vec<Check> {
             r"\d+\s*x\s*\d+",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["A 4 x 6 card."], &["A 4 × 6 card."]),
         // Curly quotes recommendation (straight double quotes)
-        Check::new(
+        Check::with_matcher(
             "typography.symbols.curly_quotes",
             "Consider using curly quotes instead of straight quotes for prose.",
-            r#"(?<!\w)"(?!\s)"#,
+            find_straight_opening_quotes,
         )
-        .raw()
         .with_severity(Severity::Suggestion)
-        .allow_in_quotes(),
+        .allow_in_quotes()
+        .examples(
+            &[r#"He said "hello" to me."#],
+            &["He said \u{201C}hello\u{201D} to me."],
+        ),
         // En dash for ranges
         Check::new(
             "typography.dashes.en_dash_range",
@@ -76,7 +91,8 @@ pub fn get_checks() -> Vec<Check> {
             r"\d+-\d+",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["Pages 10-20."], &["Pages 10–20."]),
         // Em dash usage (double hyphens)
         Check::new(
             "typography.dashes.em_dash",
@@ -85,7 +101,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{2014}"),
+        .with_replacement("\u{2014}")
+        .examples(&["Wait--what?"], &["Wait—what?"]),
         // Apostrophe in contractions
         Check::new(
             "typography.symbols.apostrophe",
@@ -93,7 +110,8 @@ pub fn get_checks() -> Vec<Check> {
             r"\w'\w",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["It's late."], &["It’s late."]),
     ];
 
     // Diacritical marks - words that should have accents/diacritics
@@ -204,7 +222,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Warning)
-        .with_replacement("!"),
+        .with_replacement("!")
+        .examples(&["Wow!!!"], &["Wow!"]),
     );
 
     // Mixed quotes
@@ -218,6 +237,30 @@ pub fn get_checks() -> Vec<Check> {
         .with_severity(Severity::Suggestion),
     );
 
+    // Primary quotation marks: double in American and Canadian, single in British and Australian
+    checks.push(
+        Check::new(
+            "typography.quotes.dialect_double",
+            "British and Australian English use single quotation marks for primary quotes.",
+            r#"[\u{201C}"][^\u{201C}\u{201D}"\n]+[\u{201D}"]"#,
+        )
+        .raw()
+        .with_severity(Severity::Suggestion)
+        .dialect(Convention::Quotes, Variant::American)
+        .allow_in_quotes(),
+    );
+
+    checks.push(
+        Check::with_matcher(
+            "typography.quotes.dialect_single",
+            "American and Canadian English use double quotation marks for primary quotes.",
+            find_single_primary_quotes,
+        )
+        .with_severity(Severity::Suggestion)
+        .dialect(Convention::Quotes, Variant::British)
+        .allow_in_quotes(),
+    );
+
     // Spaces around em dash
     checks.push(
         Check::new(
@@ -226,7 +269,8 @@ pub fn get_checks() -> Vec<Check> {
             r" \u{2014} ",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["Wait — what?"], &["Wait—what?"]),
     );
 
     // Prime vs apostrophe for feet/inches
@@ -237,7 +281,8 @@ pub fn get_checks() -> Vec<Check> {
             r#"\d+['\"]\s*\d+['\"]"#,
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["He is 6' 2\" tall."], &["He is 6′ 2″ tall."]),
     );
 
     // Degree symbol
@@ -259,7 +304,8 @@ pub fn get_checks() -> Vec<Check> {
             r"\d+\s*[xX]\s*\d+",
         )
         .raw()
-        .with_severity(Severity::Suggestion),
+        .with_severity(Severity::Suggestion)
+        .examples(&["A 2X4 board."], &["A 2×4 board."]),
     );
 
     // Fractions
@@ -271,7 +317,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{00BD}"),
+        .with_replacement("\u{00BD}")
+        .examples(&["Add 1/2 cup."], &["Add ½ cup.", "Add 11/2 cups."]),
     );
 
     checks.push(
@@ -282,7 +329,8 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{00BC}"),
+        .with_replacement("\u{00BC}")
+        .examples(&["Add 1/4 cup."], &["Add ¼ cup."]),
     );
 
     checks.push(
@@ -293,12 +341,45 @@ pub fn get_checks() -> Vec<Check> {
         )
         .raw()
         .with_severity(Severity::Suggestion)
-        .with_replacement("\u{00BE}"),
+        .with_replacement("\u{00BE}")
+        .examples(&["Add 3/4 cup."], &["Add ¾ cup."]),
     );
 
     checks
 }
 
+/// Find straight double quotes that open a quotation: not preceded by a word
+/// character and not followed by whitespace (the regex crate has no lookaround)
+fn find_straight_opening_quotes(text: &str, _config: &Config) -> Vec<CheckMatch> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    text.char_indices()
+        .filter(|&(i, c)| {
+            c == '"'
+                && !text[..i].chars().next_back().is_some_and(is_word)
+                && text[i + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|n| !n.is_whitespace())
+        })
+        .map(|(i, _)| CheckMatch::new(i, i + 1))
+        .collect()
+}
+
+/// Find single-quoted spans (\u{2018}...\u{2019}) used as primary quotations
+/// Single quotes nested inside double quotes are the American style for inner
+/// quotations, so they're skipped. Quotes are paired by `QuoteTracker`, so
+/// apostrophes (\u{2018}90s, weren\u{2019}t) aren't taken for quotation marks.
+fn find_single_primary_quotes(text: &str, _config: &Config) -> Vec<CheckMatch> {
+    let mut matches: Vec<CheckMatch> = QuoteTracker::new(text)
+        .outer_single_quotes()
+        .iter()
+        .map(|&(start, end)| CheckMatch::new(start, end))
+        .collect();
+    matches.sort_by_key(|m| m.start);
+    matches
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,4 +407,45 @@ mod tests {
         let results = em_dash_check.run("This is important--really important.");
         assert_eq!(results.len(), 1);
     }
+
+    #[test]
+    fn test_single_quotes_nested_in_double() {
+        let checks = get_checks();
+        let single = checks
+            .iter()
+            .find(|c| c.id == "typography.quotes.dialect_single")
+            .unwrap();
+
+        assert_eq!(single.run("She said \u{2018}no\u{2019} twice.").len(), 1);
+        // American inner quotation
+        let nested = "\u{201C}She said \u{2018}no\u{2019} twice,\u{201D} he said.";
+        assert!(single.run(nested).is_empty());
+        // Single quotes after the double quotation closes are primary again
+        assert_eq!(
+            single
+                .run("\u{201C}Fine,\u{201D} he said. \u{2018}No.\u{2019}")
+                .len(),
+            1
+        );
+        // An unclosed double quote doesn't carry over into the next paragraph
+        assert_eq!(
+            single
+                .run("\u{201C}Unclosed\n\n\u{2018}Quoted\u{2019}")
+                .len(),
+            1
+        );
+        // Apostrophes in elided decades and contractions aren't quotation marks
+        assert!(single
+            .run("The \u{2018}90s were fun, weren\u{2019}t they?")
+            .is_empty());
+        assert!(single
+            .run("It isn\u{2019}t late, and the \u{2018}80s weren\u{2019}t either.")
+            .is_empty());
+        assert_eq!(
+            single
+                .run("It isn\u{2019}t \u{2018}late\u{2019}, is it?")
+                .len(),
+            1
+        );
+    }
 }
@@ -2,8 +2,23 @@
 //!
 //! Detects improper comparison of absolute adjectives.
 
+use super::{keyed_examples, KeyedExamples};
 use crate::check::{Check, Severity};
 
+/// Examples for generated checks, keyed by "comparator word"
+const EXAMPLES: KeyedExamples = &[
+    (
+        "somewhat ideal",
+        &["It was a somewhat ideal spot."],
+        &["It was a somewhat idealized spot."],
+    ),
+    (
+        "quite unique",
+        &["The design is quite unique."],
+        &["The design is quite uniquely suited to it."],
+    ),
+];
+
 /// Get all uncomparable checks
 pub fn get_checks() -> Vec<Check> {
     // Words that cannot be compared (absolute adjectives)
@@ -76,16 +91,18 @@ pub fn get_checks() -> Vec<Check> {
                 uncomp, comp_name
             );
             let pattern = format!(r"{}\s+{}", comp_pattern, uncomp);
+            let key = format!("{} {}", comp_name, uncomp);
 
-            checks.push(
+            checks.push(keyed_examples(
                 Check::new(
                     Box::leak(id.into_boxed_str()),
                     Box::leak(message.into_boxed_str()),
                     Box::leak(pattern.into_boxed_str()),
                 )
-                .raw()
                 .with_severity(Severity::Warning),
-            );
+                &key,
+                EXAMPLES,
+            ));
         }
     }
 
@@ -194,6 +211,11 @@ pub fn get_checks() -> Vec<Check> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_examples_attached() {
+        crate::checks::assert_examples_attached(&get_checks(), EXAMPLES);
+    }
+
     #[test]
     fn test_very_unique() {
         let checks = get_checks();
@@ -204,4 +226,15 @@ mod tests {
         let results = check.run("This is very unique.");
         assert_eq!(results.len(), 1);
     }
+
+    #[test]
+    fn test_generated_checks_respect_word_boundaries() {
+        let checks = get_checks();
+        let check = checks
+            .iter()
+            .find(|c| c.id == "uncomparables.very_whole")
+            .unwrap();
+        assert_eq!(check.run("A very whole loaf.").len(), 1);
+        assert!(check.run("A very wholesome meal.").is_empty());
+    }
 }
@@ -2,8 +2,18 @@
 //!
 //! Handles check enabling/disabling and linting options.
 
+use crate::allowlist::{AllowEntry, AllowRegexes};
+use crate::check::Severity;
+use crate::checks;
+use crate::dialect::Dialect;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Categories that only run when enabled explicitly (`config.enable("repetition")`)
+/// Their results are stylistic and numerous on long documents
+pub const OPT_IN_CATEGORIES: &[&str] = &["repetition"];
 
 /// Configuration for the linter
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,18 +26,235 @@ pub struct Config {
     #[serde(default = "default_true")]
     pub check_quotes: bool,
 
+    /// Dialect whose spelling and quotation conventions are preferred
+    #[serde(default)]
+    pub dialect: Dialect,
+
+    /// Window and thresholds for the repetition checks
+    #[serde(default)]
+    pub repetition: RepetitionConfig,
+
+    /// Sentence and paragraph length limits
+    #[serde(default)]
+    pub length: LengthConfig,
+
+    /// How results from different checks flagging the same text are merged
+    #[serde(default)]
+    pub overlap: OverlapPolicy,
+
+    /// Also report offsets in UTF-16 code units (`LintResult::utf16_start`/`utf16_end`)
+    /// JavaScript strings and editors such as CodeMirror and Monaco index this way
+    #[serde(default)]
+    pub utf16_offsets: bool,
+
+    /// Also report offsets in characters (`LintResult::char_start`/`char_end`)
+    #[serde(default)]
+    pub char_offsets: bool,
+
     /// Enabled/disabled status for each check category
     #[serde(default)]
     pub checks: HashMap<String, bool>,
 
+    /// Severity overrides for checks or categories (longest matching prefix wins)
+    #[serde(default)]
+    pub severities: HashMap<String, Severity>,
+
+    /// Phrases and patterns whose results are suppressed (see `AllowEntry`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowlist: Vec<AllowEntry>,
+
     /// Cache for check_enabled lookups (not serialized)
     /// Maps check IDs to their enabled status for O(1) lookups
     #[serde(skip)]
     cache: HashMap<String, bool>,
 
     /// Sorted list of prefixes for efficient prefix matching (not serialized)
+    /// Built on first use, so configs created by deserialization get one too
     #[serde(skip)]
-    prefixes: Vec<String>,
+    prefixes: OnceLock<Vec<String>>,
+
+    /// Compiled allowlist regexes (not serialized)
+    /// Built on first use and rebuilt after `allow` or `load_allowlist`
+    #[serde(skip)]
+    allow_regexes: OnceLock<AllowRegexes>,
+}
+
+/// Policy for results from different checks that flag the same text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapPolicy {
+    /// Report every result
+    KeepAll,
+    /// Keep the superseding check, else the longer match, else the higher severity
+    #[default]
+    MostSpecific,
+    /// Keep the higher severity, falling back to `MostSpecific`
+    HighestSeverity,
+}
+
+/// Unit in which a repetition window or length limit is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowUnit {
+    /// Count words
+    #[default]
+    Words,
+    /// Count sentences
+    Sentences,
+}
+
+/// Settings for the echo-word and crutch-word checks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepetitionConfig {
+    /// Size of the sliding window, in `unit`s
+    pub window: usize,
+    /// Unit the window is measured in
+    pub unit: WindowUnit,
+    /// Occurrences of a word within one window before it is flagged
+    pub threshold: usize,
+    /// Minimum document-wide count for a crutch word
+    pub crutch_min_count: usize,
+    /// Minimum share of content words for a crutch word (0.02 = 2%)
+    pub crutch_min_frequency: f64,
+}
+
+impl Default for RepetitionConfig {
+    fn default() -> Self {
+        Self {
+            window: 100,
+            unit: WindowUnit::Words,
+            threshold: 3,
+            crutch_min_count: 8,
+            crutch_min_frequency: 0.02,
+        }
+    }
+}
+
+/// Limits for the `length.sentence` and `length.paragraph` checks
+/// A limit of 0 turns the check off
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LengthConfig {
+    /// Maximum words per sentence
+    pub max_sentence_words: usize,
+    /// Maximum paragraph length, in `paragraph_unit`s
+    pub max_paragraph_length: usize,
+    /// Unit the paragraph limit is measured in
+    pub paragraph_unit: WindowUnit,
+}
+
+impl Default for LengthConfig {
+    fn default() -> Self {
+        Self {
+            max_sentence_words: 40,
+            max_paragraph_length: 250,
+            paragraph_unit: WindowUnit::Words,
+        }
+    }
+}
+
+impl LengthConfig {
+    /// Limits suited to user documentation: short sentences and paragraphs
+    pub fn user_docs() -> Self {
+        Self {
+            max_sentence_words: 25,
+            max_paragraph_length: 6,
+            paragraph_unit: WindowUnit::Sentences,
+        }
+    }
+
+    /// Limits suited to academic papers
+    pub fn papers() -> Self {
+        Self::default()
+    }
+}
+
+/// Per-call settings layered over a `Config` (see `Linter::check_with_options`)
+/// Category and severity filters are applied before any check runs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LintOptions {
+    /// Only run checks in one of these categories or check groups (`typography`,
+    /// `typography.symbols`); empty runs every enabled check
+    pub categories: Vec<String>,
+    /// Only run checks at least this severe
+    pub min_severity: Option<Severity>,
+    /// Overrides `Config::max_errors`
+    pub max_errors: Option<usize>,
+    /// Overrides `Config::check_quotes`
+    pub check_quotes: Option<bool>,
+    /// Overrides `Config::utf16_offsets`
+    pub utf16_offsets: Option<bool>,
+    /// Overrides `Config::char_offsets`
+    pub char_offsets: Option<bool>,
+    /// Only lint this byte range of the text
+    /// Checks see just the range, so context outside it (an open quote, earlier
+    /// repetitions) is ignored; positions in results are still relative to the full text
+    pub range: Option<Range<usize>>,
+}
+
+impl LintOptions {
+    /// Options that only run checks in one category
+    pub fn category(category: &str) -> Self {
+        Self {
+            categories: vec![category.to_string()],
+            ..Default::default()
+        }
+    }
+
+    /// Options that only run checks at least this severe
+    pub fn min_severity(severity: Severity) -> Self {
+        Self {
+            min_severity: Some(severity),
+            ..Default::default()
+        }
+    }
+
+    /// Whether a check passes the category and severity filters
+    pub(crate) fn selects(&self, check_id: &str, severity: Severity) -> bool {
+        let in_categories = self.categories.is_empty()
+            || self
+                .categories
+                .iter()
+                .any(|category| id_covers(category, check_id));
+        let severe_enough = match self.min_severity {
+            Some(min) => severity.at_least(min),
+            None => true,
+        };
+        in_categories && severe_enough
+    }
+
+    /// The byte range to lint, clamped to the text and widened to character boundaries
+    pub(crate) fn byte_range(&self, text: &str) -> Range<usize> {
+        let Some(range) = &self.range else {
+            return 0..text.len();
+        };
+        let mut start = range.start.min(text.len());
+        while !text.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = range.end.clamp(start, text.len());
+        while !text.is_char_boundary(end) {
+            end += 1;
+        }
+        start..end
+    }
+}
+
+/// Whether `id` names the check `check_id` or a category or group containing it
+/// (`typography` covers `typography.symbols`, `typo` covers nothing)
+pub(crate) fn id_covers(id: &str, check_id: &str) -> bool {
+    check_id
+        .strip_prefix(id)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Whether a check runs when the config doesn't mention it
+fn enabled_by_default(check_id: &str) -> bool {
+    !OPT_IN_CATEGORIES
+        .iter()
+        .any(|category| id_covers(category, check_id))
 }
 
 fn default_max_errors() -> usize {
@@ -43,17 +270,30 @@ impl Default for Config {
         Self {
             max_errors: 0,
             check_quotes: true,
+            dialect: Dialect::default(),
+            repetition: RepetitionConfig::default(),
+            length: LengthConfig::default(),
+            overlap: OverlapPolicy::default(),
+            utf16_offsets: false,
+            char_offsets: false,
             checks: HashMap::new(),
+            severities: HashMap::new(),
+            allowlist: Vec::new(),
             cache: HashMap::new(),
-            prefixes: Vec::new(),
+            prefixes: OnceLock::new(),
+            allow_regexes: OnceLock::new(),
         }
     }
 }
 
 impl Config {
-    /// Create a new config with all checks enabled
+    /// Create a new config with all checks enabled, including `OPT_IN_CATEGORIES`
     pub fn all_enabled() -> Self {
-        Self::default()
+        let mut config = Self::default();
+        for category in OPT_IN_CATEGORIES {
+            config.enable(category);
+        }
+        config
     }
 
     /// Create a config with specific checks enabled
@@ -74,18 +314,25 @@ impl Config {
     /// Should be called after modifying the checks HashMap
     fn rebuild_cache(&mut self) {
         self.cache.clear();
-        self.prefixes = self.checks.keys().cloned().collect();
-        // Sort by length (descending) for better prefix matching
-        // Longer prefixes should be checked first
-        self.prefixes.sort_by_key(|b| std::cmp::Reverse(b.len()));
+        self.prefixes = OnceLock::new();
+    }
+
+    /// Configured IDs sorted by length (descending) for prefix matching
+    /// Longer prefixes should be checked first
+    fn prefixes(&self) -> &[String] {
+        self.prefixes.get_or_init(|| {
+            let mut prefixes: Vec<String> = self.checks.keys().cloned().collect();
+            prefixes.sort_by_key(|b| std::cmp::Reverse(b.len()));
+            prefixes
+        })
     }
 
     /// Check if a specific check is enabled
     /// Uses O(1) cache lookup for repeated queries
     pub fn is_check_enabled(&self, check_id: &str) -> bool {
-        // If no specific checks are configured, all are enabled
+        // If no specific checks are configured, all but the opt-in ones are enabled
         if self.checks.is_empty() {
-            return true;
+            return enabled_by_default(check_id);
         }
 
         // Check cache first for O(1) lookup
@@ -101,7 +348,7 @@ impl Config {
 
         // Check for prefix match using sorted prefixes
         // Longer prefixes are checked first for more specific matches
-        for prefix in &self.prefixes {
+        for prefix in self.prefixes() {
             if check_id.starts_with(prefix.as_str()) {
                 let enabled = self.checks.get(prefix).copied().unwrap_or(true);
                 // Don't cache here as it would require mutable access
@@ -110,7 +357,15 @@ impl Config {
         }
 
         // Default to enabled if no matching config found
-        true
+        enabled_by_default(check_id)
+    }
+
+    /// Create a config preferring the given dialect
+    pub fn with_dialect(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            ..Default::default()
+        }
     }
 
     /// Disable a specific check or category
@@ -126,6 +381,98 @@ impl Config {
         self.checks.insert(check_id.to_string(), true);
         self.rebuild_cache();
     }
+
+    /// Report a check or category at the given severity instead of its default
+    pub fn set_severity(&mut self, check_id: &str, severity: Severity) {
+        self.severities.insert(check_id.to_string(), severity);
+    }
+
+    /// Severity to report for a check, after overrides
+    pub fn severity_of(&self, check_id: &str, default: Severity) -> Severity {
+        self.severities
+            .iter()
+            .filter(|(id, _)| id_covers(id, check_id))
+            .max_by_key(|(id, _)| id.len())
+            .map_or(default, |(_, &severity)| severity)
+    }
+
+    /// Suppress results inside text matching `entry` (ignored if already listed)
+    pub fn allow(&mut self, entry: AllowEntry) {
+        self.add_allow_entries(vec![entry]);
+    }
+
+    /// Add every entry of a word list (see `AllowEntry::parse_list`)
+    /// Returns the number of new entries, or the invalid regexes (nothing is added then)
+    pub fn load_allowlist(&mut self, list: &str) -> Result<usize, String> {
+        let entries = AllowEntry::parse_list(list);
+        let errors: Vec<String> = entries
+            .iter()
+            .filter_map(|entry| entry.validate().err())
+            .collect();
+        if !errors.is_empty() {
+            return Err(errors.join("\n"));
+        }
+        Ok(self.add_allow_entries(entries))
+    }
+
+    /// Append the entries not already listed, returning how many were added
+    fn add_allow_entries(&mut self, entries: Vec<AllowEntry>) -> usize {
+        let mut seen: HashSet<&AllowEntry> = self.allowlist.iter().collect();
+        let new: Vec<AllowEntry> = entries
+            .iter()
+            .filter(|entry| seen.insert(entry))
+            .cloned()
+            .collect();
+        let added = new.len();
+        self.allowlist.extend(new);
+        self.allow_regexes = OnceLock::new();
+        added
+    }
+
+    /// Compiled regexes of the allowlist entries
+    pub(crate) fn allow_regexes(&self) -> &AllowRegexes {
+        self.allow_regexes
+            .get_or_init(|| AllowRegexes::new(&self.allowlist))
+    }
+
+    /// Warn about configured check IDs that match no available check
+    /// A known category whose cargo feature was disabled gets its own message,
+    /// since enabling or disabling it silently does nothing
+    pub fn validate(&self) -> Vec<String> {
+        let all_checks = checks::get_all_checks();
+        let mut warnings: Vec<String> = self
+            .checks
+            .keys()
+            .chain(self.severities.keys())
+            .chain(
+                self.allowlist
+                    .iter()
+                    .filter_map(|entry| entry.check.as_ref()),
+            )
+            .filter(|id| !all_checks.iter().any(|c| id_covers(id, c.id)))
+            .map(|id| {
+                let category = id.split('.').next().unwrap_or(id);
+                let compiled_in = all_checks.iter().any(|c| c.category() == category);
+                if !compiled_in && checks::category_description(category).is_some() {
+                    format!(
+                        "Check '{}' is not available: the '{}' category was compiled out \
+                         (enable the `{}` cargo feature)",
+                        id, category, category
+                    )
+                } else {
+                    format!("Unknown check '{}'", id)
+                }
+            })
+            .collect();
+        warnings.extend(
+            self.allowlist
+                .iter()
+                .filter_map(|entry| entry.validate().err()),
+        );
+        warnings.sort();
+        warnings.dedup();
+        warnings
+    }
 }
 
 #[cfg(test)]
@@ -139,6 +486,106 @@ mod tests {
         assert!(config.is_check_enabled("weasel_words.very"));
     }
 
+    #[test]
+    fn test_opt_in_categories() {
+        let mut config = Config::default();
+        assert!(!config.is_check_enabled("repetition.echo_word"));
+        assert!(!Config::with_checks(vec!["typography"]).is_check_enabled("repetition.echo_word"));
+        // Only the category itself, not IDs that merely start with its name
+        assert!(config.is_check_enabled("repetitions.other"));
+
+        config.enable("repetition.echo_word");
+        assert!(config.is_check_enabled("repetition.echo_word"));
+        assert!(!config.is_check_enabled("repetition.crutch_word"));
+        assert!(Config::all_enabled().is_check_enabled("repetition.crutch_word"));
+    }
+
+    #[cfg(feature = "typography")]
+    #[test]
+    fn test_validate_unknown_checks() {
+        let mut config = Config::default();
+        config.disable("typography.symbols");
+        config.disable("typograhpy");
+        config.set_severity("typograhpy", Severity::Error);
+        config.enable("typography.sym");
+        assert_eq!(
+            config.validate(),
+            vec![
+                "Unknown check 'typograhpy'",
+                "Unknown check 'typography.sym'"
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "terms"))]
+    #[test]
+    fn test_validate_compiled_out_category() {
+        let mut config = Config::default();
+        config.enable("terms.animal_adjectives");
+        assert!(config.validate()[0].contains("`terms` cargo feature"));
+    }
+
+    #[test]
+    fn test_severity_overrides() {
+        let mut config = Config::default();
+        assert_eq!(
+            config.severity_of("weasel_words.very", Severity::Warning),
+            Severity::Warning
+        );
+
+        config.set_severity("weasel_words", Severity::Suggestion);
+        config.set_severity("weasel_words.very", Severity::Error);
+        assert_eq!(
+            config.severity_of("weasel_words.very", Severity::Warning),
+            Severity::Error
+        );
+        assert_eq!(
+            config.severity_of("weasel_words.misc", Severity::Warning),
+            Severity::Suggestion
+        );
+        config.set_severity("weasel", Severity::Error);
+        assert_eq!(
+            config.severity_of("weasel_words.misc", Severity::Warning),
+            Severity::Suggestion
+        );
+
+        let json = serde_json::to_string(&config).unwrap();
+        let parsed: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.severities, config.severities);
+    }
+
+    #[test]
+    fn test_lint_options() {
+        let options: LintOptions =
+            serde_json::from_str(r#"{"categories": ["typography"], "min_severity": "warning"}"#)
+                .unwrap();
+        assert!(options.selects("typography.symbols.ellipsis", Severity::Error));
+        assert!(!options.selects("typography.symbols.ellipsis", Severity::Suggestion));
+        assert!(!options.selects("weasel_words.very", Severity::Error));
+        let partial = LintOptions::category("typo");
+        assert!(!partial.selects("typography.symbols.ellipsis", Severity::Error));
+        assert!(LintOptions::default().selects("weasel_words.very", Severity::Suggestion));
+
+        // Ranges are clamped to the text and widened to character boundaries
+        let text = "café au lait";
+        let range = |range| LintOptions {
+            range: Some(range),
+            ..Default::default()
+        };
+        assert_eq!(LintOptions::default().byte_range(text), 0..text.len());
+        assert_eq!(range(4..100).byte_range(text), 3..text.len());
+        assert_eq!(range(0..4).byte_range(text), 0..5);
+        assert_eq!(range(50..60).byte_range(text), text.len()..text.len());
+    }
+
+    #[test]
+    fn test_deserialized_config_matches_prefixes() {
+        let config: Config =
+            serde_json::from_str(r#"{"checks": {"weasel_words": false}}"#).unwrap();
+        assert!(!config.is_check_enabled("weasel_words.very"));
+        assert!(config.is_check_enabled("typography.symbols.ellipsis"));
+    }
+
     #[test]
     fn test_disabled_check() {
         let mut config = Config::default();
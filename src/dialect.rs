@@ -0,0 +1,227 @@
+//! Dialect module for proselint-wasm
+//!
+//! Describes which side of each American/British variant pair a dialect prefers.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// English dialect used to decide which spelling variants are flagged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    /// American English (-ize, -or, "toward", double quotes)
+    #[default]
+    American,
+    /// British English (-ise, -our, "towards", single quotes)
+    British,
+    /// Canadian English (-ize, but -our and -re)
+    Canadian,
+    /// Australian English (follows British conventions)
+    Australian,
+    /// No preferred dialect: only flag documents that mix both sides of a pair
+    Any,
+}
+
+/// A spelling or punctuation convention on which dialects disagree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Convention {
+    /// organize / organise, analyze / analyse
+    Ize,
+    /// color / colour
+    Our,
+    /// center / centre
+    Re,
+    /// defense / defence
+    Ence,
+    /// traveled / travelled
+    DoubledL,
+    /// toward / towards
+    Wards,
+    /// learned / learnt
+    PastTense,
+    /// among / amongst, while / whilst
+    Among,
+    /// Other one-off spellings (gray / grey, catalog / catalogue)
+    Spelling,
+    /// Double or single quotation marks for primary quotes
+    Quotes,
+}
+
+/// Which side of a variant pair a check flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// The American form
+    American,
+    /// The British form
+    British,
+}
+
+impl Dialect {
+    /// All dialects that express a preference
+    pub const PREFERRING: [Dialect; 4] = [
+        Dialect::American,
+        Dialect::British,
+        Dialect::Canadian,
+        Dialect::Australian,
+    ];
+
+    /// Convert to lowercase string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dialect::American => "american",
+            Dialect::British => "british",
+            Dialect::Canadian => "canadian",
+            Dialect::Australian => "australian",
+            Dialect::Any => "any",
+        }
+    }
+
+    /// Human-readable name (e.g., "British")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dialect::American => "American",
+            Dialect::British => "British",
+            Dialect::Canadian => "Canadian",
+            Dialect::Australian => "Australian",
+            Dialect::Any => "any",
+        }
+    }
+
+    /// The variant this dialect prefers for a convention
+    /// Returns None for `Dialect::Any`, which has no preference
+    pub fn preferred(&self, convention: Convention) -> Option<Variant> {
+        match self {
+            Dialect::American => Some(Variant::American),
+            Dialect::British | Dialect::Australian => Some(Variant::British),
+            Dialect::Canadian => Some(match convention {
+                Convention::Our
+                | Convention::Re
+                | Convention::Ence
+                | Convention::DoubledL
+                | Convention::Spelling => Variant::British,
+                Convention::Ize
+                | Convention::Wards
+                | Convention::PastTense
+                | Convention::Among
+                | Convention::Quotes => Variant::American,
+            }),
+            Dialect::Any => None,
+        }
+    }
+
+    /// Returns true if a check flagging `variant` of `convention` should run
+    pub fn flags(&self, convention: Convention, variant: Variant) -> bool {
+        self.preferred(convention)
+            .is_some_and(|preferred| preferred != variant)
+    }
+}
+
+impl Convention {
+    /// Convert to snake_case string representation (used in check IDs)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Convention::Ize => "ize",
+            Convention::Our => "our",
+            Convention::Re => "re",
+            Convention::Ence => "ence",
+            Convention::DoubledL => "doubled_l",
+            Convention::Wards => "wards",
+            Convention::PastTense => "past_tense",
+            Convention::Among => "among",
+            Convention::Spelling => "spelling",
+            Convention::Quotes => "quotes",
+        }
+    }
+}
+
+impl Variant {
+    /// The other side of the pair
+    pub fn opposite(&self) -> Variant {
+        match self {
+            Variant::American => Variant::British,
+            Variant::British => Variant::American,
+        }
+    }
+
+    /// Human-readable name (e.g., "American")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Variant::American => "American",
+            Variant::British => "British",
+        }
+    }
+}
+
+/// List the dialects preferring `variant` of `convention` (e.g., "British and Australian")
+pub fn preferring_dialects(convention: Convention, variant: Variant) -> String {
+    let names: Vec<&str> = Dialect::PREFERRING
+        .iter()
+        .filter(|d| d.preferred(convention) == Some(variant))
+        .map(|d| d.name())
+        .collect();
+
+    match names.as_slice() {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [init @ .., last] => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Dialect {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "american" | "en-us" => Ok(Dialect::American),
+            "british" | "en-gb" => Ok(Dialect::British),
+            "canadian" | "en-ca" => Ok(Dialect::Canadian),
+            "australian" | "en-au" => Ok(Dialect::Australian),
+            "any" => Ok(Dialect::Any),
+            _ => Err(
+                "Invalid dialect: expected 'american', 'british', 'canadian', 'australian', or 'any'",
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialect_preferences() {
+        assert!(Dialect::American.flags(Convention::Wards, Variant::British));
+        assert!(!Dialect::American.flags(Convention::Wards, Variant::American));
+        assert!(Dialect::British.flags(Convention::Ize, Variant::American));
+        assert!(Dialect::Canadian.flags(Convention::Ize, Variant::British));
+        assert!(Dialect::Canadian.flags(Convention::Our, Variant::American));
+        assert!(!Dialect::Any.flags(Convention::Our, Variant::American));
+        assert!(!Dialect::Any.flags(Convention::Our, Variant::British));
+    }
+
+    #[test]
+    fn test_dialect_from_str() {
+        assert_eq!("British".parse(), Ok(Dialect::British));
+        assert_eq!("en-au".parse(), Ok(Dialect::Australian));
+        assert!("klingon".parse::<Dialect>().is_err());
+    }
+
+    #[test]
+    fn test_preferring_dialects() {
+        assert_eq!(
+            preferring_dialects(Convention::Our, Variant::British),
+            "British, Canadian and Australian"
+        );
+        assert_eq!(
+            preferring_dialects(Convention::Ize, Variant::American),
+            "American and Canadian"
+        );
+    }
+}
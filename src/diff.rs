@@ -0,0 +1,490 @@
+//! Diff module for proselint-wasm
+//!
+//! Finds the lines of a new version that a change added or modified, from a
+//! unified diff or from the old and new text, so a pull request is only
+//! flagged for the prose its author touched.
+
+use crate::cache::ResultCache;
+use crate::config::{Config, LintOptions};
+use crate::engine;
+use crate::position::LineTracker;
+use crate::LintResult;
+use std::ops::Range;
+
+/// Edit distance past which `ChangedLines::between` stops aligning lines and
+/// treats everything between the common start and end as changed
+const MAX_EDITS: usize = 1024;
+
+/// Added or modified lines of a text (1-indexed)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedLines {
+    /// Sorted, disjoint, non-adjacent line ranges
+    ranges: Vec<Range<usize>>,
+}
+
+impl ChangedLines {
+    /// No changed lines
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark lines as changed
+    pub fn from_lines(lines: impl IntoIterator<Item = usize>) -> Self {
+        let mut changed = Self::new();
+        for line in lines {
+            changed.insert(line..line + 1);
+        }
+        changed
+    }
+
+    /// Lines of `new` that differ from `old`, found with a line diff
+    /// Line endings are ignored, so converting `\r\n` to `\n` changes nothing
+    pub fn between(old: &str, new: &str) -> Self {
+        let old_lines = lines_of(old);
+        let new_lines = lines_of(new);
+        Self::from_lines(
+            added_lines(&old_lines, &new_lines)
+                .into_iter()
+                .map(|idx| idx + 1),
+        )
+    }
+
+    /// Added lines from a unified diff of one file (`git diff`, `diff -u`)
+    /// Returns an error for malformed hunks or a diff covering several files
+    /// (use `parse_unified_diff` for those)
+    pub fn from_unified_diff(diff: &str) -> Result<Self, String> {
+        let mut files = parse_unified_diff(diff)?;
+        match files.len() {
+            0 => Ok(Self::new()),
+            1 => Ok(files.remove(0).lines),
+            n => Err(format!(
+                "Diff covers {} files; pick one with parse_unified_diff",
+                n
+            )),
+        }
+    }
+
+    /// Mark a range of lines as changed
+    pub fn insert(&mut self, lines: Range<usize>) {
+        if lines.is_empty() {
+            return;
+        }
+        // First range that ends at or after the new one starts (adjacent ranges merge)
+        let first = self.ranges.partition_point(|r| r.end < lines.start);
+        let last = self.ranges.partition_point(|r| r.start <= lines.end);
+        let merged = if first < last {
+            self.ranges[first].start.min(lines.start)..self.ranges[last - 1].end.max(lines.end)
+        } else {
+            lines
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Whether a line is changed
+    pub fn contains(&self, line: usize) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end <= line);
+        self.ranges.get(idx).is_some_and(|r| r.start <= line)
+    }
+
+    /// Changed line ranges, sorted
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    /// Number of changed lines
+    pub fn line_count(&self) -> usize {
+        self.ranges.iter().map(|r| r.len()).sum()
+    }
+
+    /// True if no line changed
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+/// Changed lines of one file in a unified diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChanges {
+    /// Path of the new version, without the `b/` prefix (empty if the diff has no headers)
+    pub path: String,
+    /// Added lines, numbered in the new version
+    pub lines: ChangedLines,
+}
+
+/// Parse a unified diff into the added lines of each file
+/// Deleted files (`+++ /dev/null`) are skipped
+pub fn parse_unified_diff(diff: &str) -> Result<Vec<FileChanges>, String> {
+    let mut files: Vec<FileChanges> = Vec::new();
+    // Lines left in the current hunk, in the old and new version
+    let mut old_left = 0;
+    let mut new_left = 0;
+    let mut new_line = 0;
+
+    for (idx, line) in diff.lines().enumerate() {
+        if old_left > 0 || new_left > 0 {
+            let file = files.last_mut().expect("hunks belong to a file");
+            match line.chars().next() {
+                // Some tools strip the space from empty context lines
+                Some(' ') | None if old_left > 0 && new_left > 0 => {
+                    old_left -= 1;
+                    new_left -= 1;
+                    new_line += 1;
+                }
+                Some('+') if new_left > 0 => {
+                    file.lines.insert(new_line..new_line + 1);
+                    new_left -= 1;
+                    new_line += 1;
+                }
+                Some('-') if old_left > 0 => old_left -= 1,
+                Some('\\') => {}
+                _ => {
+                    return Err(format!(
+                        "Malformed diff at line {}: hunk ended early",
+                        idx + 1
+                    ))
+                }
+            }
+            continue;
+        }
+
+        if let Some(path) = line.strip_prefix("+++ ") {
+            // Drop a trailing timestamp and git's `b/` prefix
+            let path = path.split('\t').next().unwrap_or(path).trim_end();
+            files.push(FileChanges {
+                path: path.strip_prefix("b/").unwrap_or(path).to_string(),
+                lines: ChangedLines::new(),
+            });
+        } else if line.starts_with("@@") {
+            let (old_count, new_start, new_count) = parse_hunk_header(line)
+                .ok_or_else(|| format!("Malformed hunk header at line {}: {}", idx + 1, line))?;
+            if files.is_empty() {
+                files.push(FileChanges {
+                    path: String::new(),
+                    lines: ChangedLines::new(),
+                });
+            }
+            old_left = old_count;
+            new_left = new_count;
+            new_line = new_start;
+        }
+        // Anything else (`diff --git`, `index`, `---`, ...) is a header
+    }
+
+    if old_left > 0 || new_left > 0 {
+        return Err("Malformed diff: last hunk is incomplete".to_string());
+    }
+    files.retain(|file| file.path != "/dev/null");
+    Ok(files)
+}
+
+/// Lint `text` and keep the results that touch a changed line
+///
+/// Only the paragraphs containing changed lines are linted, so phrases spanning
+/// an edit boundary still match. Document-wide results (crutch words, the "any"
+/// dialect's majority) only see those paragraphs and can differ from a full lint.
+pub(crate) fn lint_changed(
+    text: &str,
+    config: &Config,
+    changed: &ChangedLines,
+    mut cache: Option<&mut ResultCache>,
+) -> Vec<LintResult> {
+    let tracker = LineTracker::new(text);
+    let options = LintOptions {
+        max_errors: Some(0),
+        ..Default::default()
+    };
+
+    // Byte spans of the changed lines, and of the paragraphs around them
+    let mut changed_spans: Vec<Range<usize>> = Vec::new();
+    let mut paragraphs: Vec<Range<usize>> = Vec::new();
+    for lines in changed.ranges() {
+        let last = (lines.end - 1).min(tracker.line_count());
+        let (Some(first_span), Some(last_span)) =
+            (tracker.line_span(lines.start), tracker.line_span(last))
+        else {
+            continue;
+        };
+        changed_spans.push(first_span.start..last_span.end);
+
+        let paragraph = paragraph_around(text, &tracker, lines.start, last);
+        match paragraphs.last_mut() {
+            Some(previous) if previous.end >= paragraph.start => {
+                previous.end = previous.end.max(paragraph.end)
+            }
+            _ => paragraphs.push(paragraph),
+        }
+    }
+
+    let touches_change = |result: &LintResult| {
+        changed_spans.iter().any(|span| {
+            if result.start == result.end {
+                span.contains(&result.start)
+            } else {
+                result.start < span.end && span.start < result.end
+            }
+        })
+    };
+
+    let mut results: Vec<LintResult> = Vec::new();
+    for paragraph in paragraphs {
+        results.extend(match cache.as_deref_mut() {
+            // Paragraphs unchanged since an earlier run hit the cache
+            Some(cache) => cache.lint_span(text, paragraph, &tracker, config),
+            None => engine::lint_span(text, paragraph, &tracker, config, &options),
+        });
+    }
+    results.retain(touches_change);
+    if config.max_errors > 0 {
+        results.truncate(config.max_errors);
+    }
+    results
+}
+
+/// Byte range of the paragraphs (runs of non-blank lines) around lines `first..=last`
+fn paragraph_around(text: &str, tracker: &LineTracker, first: usize, last: usize) -> Range<usize> {
+    let is_blank = |line: usize| {
+        tracker
+            .line_span(line)
+            .is_none_or(|span| text[span].trim().is_empty())
+    };
+
+    let mut start_line = first;
+    while start_line > 1 && !is_blank(start_line - 1) {
+        start_line -= 1;
+    }
+    let mut end_line = last;
+    while !is_blank(end_line + 1) {
+        end_line += 1;
+    }
+
+    let start = tracker.line_span(start_line).map_or(0, |span| span.start);
+    let end = tracker
+        .line_span(end_line)
+        .map_or(text.len(), |span| span.end);
+    start..end
+}
+
+/// `@@ -l[,s] +l[,s] @@` → (old line count, new start line, new line count)
+fn parse_hunk_header(line: &str) -> Option<(usize, usize, usize)> {
+    let mut parts = line.strip_prefix("@@ ")?.split(' ');
+    let old = parts.next()?.strip_prefix('-')?;
+    let new = parts.next()?.strip_prefix('+')?;
+
+    let range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (_, old_count) = range(old)?;
+    let (new_start, new_count) = range(new)?;
+    Some((old_count, new_start, new_count))
+}
+
+/// Lines of `text` without their line endings, split like `LineTracker`
+fn lines_of(text: &str) -> Vec<&str> {
+    let tracker = LineTracker::new(text);
+    (1..=tracker.line_count())
+        .filter_map(|line| tracker.line_span(line))
+        .map(|span| text[span].trim_end_matches(['\r', '\n']))
+        .collect()
+}
+
+/// 0-based indexes of lines in `new` that aren't part of a longest common
+/// subsequence with `old` (Myers' O(ND) diff)
+fn added_lines(old: &[&str], new: &[&str]) -> Vec<usize> {
+    // Common lines at the start and end never change
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    match myers_added(a, b) {
+        Some(added) => added.into_iter().map(|idx| idx + prefix).collect(),
+        None => (prefix..new.len() - suffix).collect(),
+    }
+}
+
+/// Indexes of `b` inserted by a shortest edit script from `a`, or `None`
+/// if that script is longer than `MAX_EDITS`
+fn myers_added(a: &[&str], b: &[&str]) -> Option<Vec<usize>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (a.len() + b.len()).min(MAX_EDITS) as isize;
+    let offset = max + 1;
+    // Furthest x reached on each diagonal k = x - y
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    // `trace[d]` holds v for diagonals -d..=d after round d
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    for d in 0..=max {
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+
+            if x >= n && y >= m {
+                trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+                return Some(backtrack(&trace, n, m));
+            }
+        }
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+    }
+    None
+}
+
+/// Walk the rounds of `myers_added` back from (n, m), collecting insertions
+fn backtrack(trace: &[Vec<isize>], n: isize, m: isize) -> Vec<usize> {
+    let mut added = Vec::new();
+    let (mut x, mut y) = (n, m);
+
+    for d in (1..trace.len() as isize).rev() {
+        let previous = &trace[d as usize - 1];
+        let at = |k: isize| previous[(k + d - 1) as usize];
+        let k = x - y;
+        let down = k == -d || (k != d && at(k - 1) < at(k + 1));
+        let prev_k = if down { k + 1 } else { k - 1 };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        if down {
+            added.push(prev_y as usize);
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+
+    added.reverse();
+    added
+}
+
+#[cfg(test)]
+// Expected ranges are written as slices, some of them with a single range
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_lines_insert() {
+        let mut changed = ChangedLines::from_lines([5, 2, 3, 9]);
+        assert_eq!(changed.ranges(), &[2..4, 5..6, 9..10]);
+        changed.insert(4..5);
+        assert_eq!(changed.ranges(), &[2..6, 9..10]);
+        changed.insert(1..12);
+        assert_eq!(changed.ranges(), &[1..12]);
+        assert!(changed.contains(11) && !changed.contains(12) && !changed.contains(0));
+        assert_eq!(changed.line_count(), 11);
+    }
+
+    #[test]
+    fn test_changed_lines_between() {
+        let old = "one\ntwo\nthree\nfour\nfive\n";
+        let new = "one\r\nTWO\r\nthree\r\ninserted\r\nfour\r\nfive\r\nsix\r\n";
+        assert_eq!(
+            ChangedLines::between(old, new).ranges(),
+            &[2..3, 4..5, 7..8]
+        );
+
+        assert!(ChangedLines::between(old, old).is_empty());
+        assert!(ChangedLines::between(old, "one\nfive\n").is_empty());
+        assert_eq!(ChangedLines::between("", "a\nb").ranges(), &[1..3]);
+    }
+
+    #[test]
+    fn test_changed_lines_between_falls_back() {
+        // More edits than MAX_EDITS: every line between the common ends counts
+        let old: String = (0..2000).map(|i| format!("old {}\n", i)).collect();
+        let new: String = (0..2000).map(|i| format!("new {}\n", i)).collect();
+        let new = format!("same\n{}same\n", new);
+        let old = format!("same\n{}same\n", old);
+        assert_eq!(ChangedLines::between(&old, &new).ranges(), &[2..2002]);
+    }
+
+    #[test]
+    fn test_parse_unified_diff() {
+        let diff = "\
+diff --git a/docs/intro.md b/docs/intro.md
+index 3b18e51..a9c3f2d 100644
+--- a/docs/intro.md
++++ b/docs/intro.md
+@@ -1,4 +1,5 @@
+ # Intro
+-It was good.
++It was very good.
++--- a new rule
+
+ The end.
+@@ -10,2 +11,2 @@ Section
+ Context
+-old
++new
+\\ No newline at end of file
+diff --git a/old.md b/old.md
+deleted file mode 100644
+--- a/old.md
++++ /dev/null
+@@ -1 +0,0 @@
+-Gone.
+";
+        let files = parse_unified_diff(diff).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "docs/intro.md");
+        assert_eq!(files[0].lines.ranges(), &[2..4, 12..13]);
+
+        let single = "@@ -1 +1,2 @@\n-a\n+b\n+c\n";
+        assert_eq!(
+            ChangedLines::from_unified_diff(single).unwrap().ranges(),
+            &[1..3]
+        );
+
+        assert!(parse_unified_diff("@@ -1,2 +1,2 @@\n-a\n+b\n").is_err());
+        assert!(parse_unified_diff("+++ b/x\n@@ nonsense @@\n").is_err());
+        let two = "+++ b/a\n@@ -1 +1 @@\n-a\n+b\n+++ b/c\n@@ -1 +1 @@\n-c\n+d\n";
+        assert!(ChangedLines::from_unified_diff(two)
+            .unwrap_err()
+            .contains("2 files"));
+    }
+
+    #[cfg(all(feature = "lexical_illusions", feature = "uncomparables"))]
+    #[test]
+    fn test_lint_changed() {
+        use crate::Linter;
+
+        let old = "I saw the\ncat sleep.\n\nIt was very unique.\n";
+        // Line 2 is edited; the repeated "the" spans the unchanged line 1 and line 2
+        let new = "I saw the\nthe cat sleep.\n\nIt was very unique.\n";
+        let changed = ChangedLines::between(old, new);
+        assert_eq!(changed.ranges(), &[2..3]);
+
+        let results = lint_changed(new, &Config::default(), &changed, None);
+        assert!(results
+            .iter()
+            .any(|r| r.check == "lexical_illusions.repeated_word"));
+        // The untouched paragraph isn't reported
+        assert!(results.iter().all(|r| r.line <= 2));
+        assert!(!Linter::new().check(new).iter().all(|r| r.line <= 2));
+
+        // Going through a cache gives the same results
+        let mut cache = ResultCache::default();
+        let cached = lint_changed(new, &Config::default(), &changed, Some(&mut cache));
+        assert_eq!(cached, results);
+        // Only the changed paragraph is looked up, and it hits the next time
+        assert_eq!(cache.len(), 1);
+        let cached = lint_changed(new, &Config::default(), &changed, Some(&mut cache));
+        assert_eq!(cached, results);
+        assert_eq!(cache.stats().hits, 1);
+    }
+}
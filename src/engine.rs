@@ -1,13 +1,20 @@
 //! Core linting engine for proselint-wasm
 //!
 //! Uses Aho-Corasick for fast keyword pre-filtering before running expensive regexes.
+//! Checks without a plain keyword are pre-filtered by `RegexSet` scans instead.
 
-use crate::check::{get_cached_regex, Check, Severity};
+use crate::allowlist::AllowedSpans;
+use crate::check::{get_cached_regex, Check, CheckMatch, Severity};
 use crate::checks;
-use crate::position::{LineTracker, QuoteTracker};
-use crate::{Config, LintResult};
+use crate::dialect::{Convention, Dialect, Variant};
+use crate::position::{LineTracker, ProtectedSpanTracker, QuoteTracker};
+use crate::{Config, LintOptions, LintResult, OverlapPolicy};
 use aho_corasick::AhoCorasick;
-use std::collections::HashSet;
+use regex::RegexSetBuilder;
+use regex_syntax::hir::literal::{ExtractKind, Extractor};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::sync::OnceLock;
 
 /// Pre-built Aho-Corasick automaton for fast keyword scanning
@@ -16,35 +23,91 @@ struct AcIndex {
     ac: AhoCorasick,
     /// Map from AC pattern index to check indices (a keyword may appear in multiple checks)
     pattern_to_checks: Vec<Vec<usize>>,
-    /// Checks that need pure regex (no simple keyword to extract)
-    pure_regex_checks: Vec<usize>,
+    /// Checks that need pure regex (no simple keyword to extract), scanned together
+    regex_sets: Vec<RegexGroup>,
+    /// Checks that must always run (matchers, and regexes no set could hold)
+    unfiltered_checks: Vec<usize>,
 }
 
+/// A `RegexSet` over several pure-regex checks
+/// One scan reports which checks match anywhere, so only those run `find_iter`
+struct RegexGroup {
+    set: regex::RegexSet,
+    /// Map from set pattern index to check index
+    checks: Vec<usize>,
+}
+
+/// Number of patterns per `RegexSet`
+/// Fewer, larger sets mean fewer passes over the text
+const REGEX_SET_SIZE: usize = 256;
+
+/// Lazy DFA cache per `RegexSet`, in bytes (the regex crate defaults to 2 MB)
+/// A set this size thrashes the default cache on document-length text and falls
+/// back to a much slower engine; the cache grows only as states are needed
+const REGEX_SET_DFA_CACHE: usize = 8 << 20;
+
+/// Categories whose results span sentences, paragraphs or the document
+/// They are kept alongside the phrase results inside them instead of merged
+const STRUCTURAL_CATEGORIES: &[&str] = &["length", "repetition"];
+
 /// Global AC index - built once on first use
 static AC_INDEX: OnceLock<AcIndex> = OnceLock::new();
 
-/// Extract a simple keyword from a check pattern (if possible)
-/// Returns None if the pattern is too complex (has regex metacharacters)
-fn extract_keyword(check: &Check) -> Option<String> {
-    let pattern = check.pattern;
+/// Shortest keyword worth pre-filtering on; shorter literals match almost any text
+const MIN_KEYWORD_LEN: usize = 2;
 
-    // Skip patterns with regex metacharacters that make keyword extraction unreliable
-    // We want simple word/phrase patterns only
-    let metacharacters = [
-        '[', ']', '(', ')', '{', '}', '|', '*', '+', '?', '^', '$', '.', '\\',
-    ];
+/// Extract the literals a check's pattern requires, lowercased for the AC scan
+/// Every match starts with one of the prefixes (or ends with one of the suffixes),
+/// so the check can be skipped when none of them appear in the text.
+/// Returns None if the pattern has no usable literal factor.
+fn extract_keywords(check: &Check) -> Option<Vec<String>> {
+    // Parse without the case-insensitive flag so case variants aren't expanded;
+    // keywords are lowercased and matched against lowercased text instead
+    let hir = regex_syntax::Parser::new().parse(check.pattern).ok()?;
 
-    if pattern.chars().any(|c| metacharacters.contains(&c)) {
-        return None;
-    }
+    [ExtractKind::Prefix, ExtractKind::Suffix]
+        .into_iter()
+        .filter_map(|kind| {
+            let seq = Extractor::new().kind(kind).extract(&hir);
+            let mut keywords = seq
+                .literals()?
+                .iter()
+                .map(|lit| std::str::from_utf8(lit.as_bytes()).map(str::to_lowercase))
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?;
+            keywords.sort();
+            keywords.dedup();
+            let shortest = keywords.iter().map(String::len).min()?;
+            (shortest >= MIN_KEYWORD_LEN).then_some((shortest, keywords))
+        })
+        // Prefer whichever side has the longest shortest literal (fewer false hits)
+        .max_by_key(|(shortest, _)| *shortest)
+        .map(|(_, keywords)| keywords)
+}
 
-    // For simple patterns, return the pattern as lowercase keyword
-    let keyword = pattern.to_lowercase();
-    if keyword.len() >= 2 {
-        Some(keyword)
-    } else {
-        None
+/// A looser form of a check pattern for `RegexSet` pre-filtering
+/// Word boundary assertions are removed: Unicode `\b` forces the set off its fast
+/// DFA on non-ASCII text, and dropping an assertion can only add matches, never lose one
+fn prefilter_pattern(pattern: &str) -> String {
+    let mut loose = String::with_capacity(pattern.len() + 4);
+    loose.push_str("(?i)");
+
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            loose.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('b' | 'B') => {}
+            Some(escaped) => {
+                loose.push(c);
+                loose.push(escaped);
+            }
+            None => loose.push(c),
+        }
     }
+    loose
 }
 
 /// Build the Aho-Corasick index from all checks
@@ -56,26 +119,51 @@ fn build_ac_index() -> AcIndex {
         std::collections::HashMap::new();
     let mut pattern_to_checks: Vec<Vec<usize>> = Vec::new();
     let mut pure_regex_checks: Vec<usize> = Vec::new();
+    let mut unfiltered_checks: Vec<usize> = Vec::new();
 
     for (check_idx, check) in all_checks.iter().enumerate() {
-        if let Some(keyword) = extract_keyword(check) {
-            // Check if we already have this keyword
-            if let Some(&kw_idx) = keyword_to_index.get(&keyword) {
-                // Add this check to existing keyword
-                pattern_to_checks[kw_idx].push(check_idx);
-            } else {
-                // New keyword
-                let kw_idx = keywords.len();
-                keyword_to_index.insert(keyword.clone(), kw_idx);
-                keywords.push(keyword);
-                pattern_to_checks.push(vec![check_idx]);
+        if check.matcher.is_some() {
+            // Matchers have no pattern to pre-filter on
+            unfiltered_checks.push(check_idx);
+        } else if let Some(check_keywords) = extract_keywords(check) {
+            for keyword in check_keywords {
+                // Check if we already have this keyword
+                if let Some(&kw_idx) = keyword_to_index.get(&keyword) {
+                    // Add this check to existing keyword
+                    pattern_to_checks[kw_idx].push(check_idx);
+                } else {
+                    // New keyword
+                    let kw_idx = keywords.len();
+                    keyword_to_index.insert(keyword.clone(), kw_idx);
+                    keywords.push(keyword);
+                    pattern_to_checks.push(vec![check_idx]);
+                }
             }
         } else {
-            // No simple keyword - must run regex unconditionally
+            // No literal factor - scanned by the regex sets instead
             pure_regex_checks.push(check_idx);
         }
     }
 
+    let mut regex_sets = Vec::new();
+    for chunk in pure_regex_checks.chunks(REGEX_SET_SIZE) {
+        let patterns = chunk
+            .iter()
+            .map(|&i| prefilter_pattern(all_checks[i].pattern));
+        match RegexSetBuilder::new(patterns)
+            .dfa_size_limit(REGEX_SET_DFA_CACHE)
+            .build()
+        {
+            Ok(set) => regex_sets.push(RegexGroup {
+                set,
+                checks: chunk.to_vec(),
+            }),
+            // A pattern that doesn't compile (reported by `validate_all_checks`) or a
+            // set over the size limit: fall back to running these checks on every call
+            Err(_) => unfiltered_checks.extend_from_slice(chunk),
+        }
+    }
+
     // Build the automaton (case-insensitive matching)
     let ac = AhoCorasick::builder()
         .ascii_case_insensitive(true)
@@ -85,7 +173,21 @@ fn build_ac_index() -> AcIndex {
     AcIndex {
         ac,
         pattern_to_checks,
-        pure_regex_checks,
+        regex_sets,
+        unfiltered_checks,
+    }
+}
+
+impl AcIndex {
+    /// Indices of the checks whose keywords appear in `text_lower`
+    /// Overlapping iteration so a short keyword ("toward") can't hide a longer one
+    /// starting at the same place ("towards")
+    fn keyword_checks<'a>(&'a self, text_lower: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.ac.find_overlapping_iter(text_lower).flat_map(|mat| {
+            self.pattern_to_checks[mat.pattern().as_usize()]
+                .iter()
+                .copied()
+        })
     }
 }
 
@@ -113,77 +215,376 @@ pub fn warm_all_checks() -> usize {
     count
 }
 
+/// Whether a check should run under this config
+/// Dialect checks only run when the dialect prefers the other side of the pair,
+/// except in "any" mode where both sides run and are reconciled afterwards
+fn should_run(check: &Check, config: &Config) -> bool {
+    if !config.is_check_enabled(check.id) {
+        return false;
+    }
+
+    match check.dialect {
+        Some((convention, variant)) => {
+            config.dialect == Dialect::Any || config.dialect.flags(convention, variant)
+        }
+        None => true,
+    }
+}
+
+/// Keep only the minority side of each convention a document mixes ("any" dialect mode)
+/// Ties go against the side that appears later in the text
+fn mixed_variant_results(hits: Vec<(Convention, Variant, LintResult)>) -> Vec<LintResult> {
+    // One word can be flagged by several checks ("towards" by needless_variants and
+    // preferred_forms), so each side counts distinct spans rather than results
+    let mut spans: HashSet<(Convention, Variant, usize, usize)> = HashSet::new();
+    // Per convention: (count, first start) for the American and British sides
+    let mut tally: HashMap<Convention, [(usize, usize); 2]> = HashMap::new();
+    for (convention, variant, result) in &hits {
+        let entry = tally
+            .entry(*convention)
+            .or_insert([(0, usize::MAX), (0, usize::MAX)]);
+        let side = &mut entry[*variant as usize];
+        if spans.insert((*convention, *variant, result.start, result.end)) {
+            side.0 += 1;
+        }
+        side.1 = side.1.min(result.start);
+    }
+
+    hits.into_iter()
+        .filter_map(|(convention, variant, mut result)| {
+            let [american, british] = tally[&convention];
+            if american.0 == 0 || british.0 == 0 {
+                return None;
+            }
+            let minority = match american.0.cmp(&british.0) {
+                std::cmp::Ordering::Less => Variant::American,
+                std::cmp::Ordering::Greater => Variant::British,
+                std::cmp::Ordering::Equal if american.1 > british.1 => Variant::American,
+                std::cmp::Ordering::Equal => Variant::British,
+            };
+            if variant != minority {
+                return None;
+            }
+            result.message = format!(
+                "Inconsistent with the rest of the document, which follows {} conventions.",
+                variant.opposite().name()
+            );
+            Some(result)
+        })
+        .collect()
+}
+
+/// Returns true if two results flag substantially the same text
+/// Spans must overlap by at least half of the longer one
+fn is_duplicate(a: &LintResult, b: &LintResult) -> bool {
+    let overlap = a.end.min(b.end).saturating_sub(a.start.max(b.start));
+    let longer = (a.end - a.start).max(b.end - b.start);
+    overlap > 0 && overlap * 2 >= longer
+}
+
+/// Order two duplicate results; `Ordering::Greater` means `a` should be kept
+fn compare_results(a: &LintResult, b: &LintResult, policy: OverlapPolicy) -> Ordering {
+    let severity = |r: &LintResult| r.severity.parse::<Severity>().unwrap_or_default();
+    let supersedes = |x: &LintResult, y: &LintResult| {
+        checks::get_check(&x.check).is_some_and(|c| c.supersedes_check(&y.check))
+    };
+
+    let by_severity = severity(a).cmp(&severity(b));
+    let most_specific = supersedes(a, b)
+        .cmp(&supersedes(b, a))
+        .then((a.end - a.start).cmp(&(b.end - b.start)))
+        .then(by_severity)
+        .then(a.replacement.is_some().cmp(&b.replacement.is_some()))
+        // Stable fallback: the alphabetically first check ID wins
+        .then(b.check.cmp(&a.check));
+
+    match policy {
+        OverlapPolicy::HighestSeverity => by_severity.then(most_specific),
+        OverlapPolicy::KeepAll | OverlapPolicy::MostSpecific => most_specific,
+    }
+}
+
+/// Merge results from different checks that flag the same text
+/// The surviving result lists the merged-away check IDs in `merged`
+fn resolve_overlaps(mut results: Vec<LintResult>, policy: OverlapPolicy) -> Vec<LintResult> {
+    if policy == OverlapPolicy::KeepAll {
+        return results;
+    }
+
+    // Checks run in hash order; sort first so merging is deterministic
+    results.sort_by(|a, b| {
+        a.start
+            .cmp(&b.start)
+            .then(b.end.cmp(&a.end))
+            .then(a.check.cmp(&b.check))
+    });
+
+    // Structural results (long sentences, echoed words) cover text rather than
+    // flag a phrase, so they are never merged with the results inside them
+    let is_phrase = |r: &LintResult| !STRUCTURAL_CATEGORIES.contains(&r.category());
+
+    let mut kept: Vec<LintResult> = Vec::with_capacity(results.len());
+    for mut result in results {
+        if !is_phrase(&result) {
+            kept.push(result);
+            continue;
+        }
+
+        // Registry IDs aren't unique, so results from the same check ID merge too
+        let duplicate = kept
+            .iter_mut()
+            .rev()
+            .find(|k| is_phrase(k) && is_duplicate(k, &result));
+
+        match duplicate {
+            Some(existing) => {
+                if compare_results(&result, existing, policy) == Ordering::Greater {
+                    std::mem::swap(existing, &mut result);
+                }
+                for id in std::iter::once(result.check).chain(result.merged) {
+                    if id != existing.check && !existing.merged.contains(&id) {
+                        existing.merged.push(id);
+                    }
+                }
+            }
+            None => kept.push(result),
+        }
+    }
+
+    kept
+}
+
 /// Lint the provided text using Aho-Corasick pre-filtering
 pub fn lint_text(text: &str, config: &Config) -> Vec<LintResult> {
-    let mut results = Vec::new();
+    lint_with_options(text, config, &LintOptions::default())
+}
 
-    // Create position trackers
+/// Lint the provided text with per-call options layered over the config
+/// Checks filtered out by the options are never run
+pub fn lint_with_options(text: &str, config: &Config, options: &LintOptions) -> Vec<LintResult> {
     let line_tracker = LineTracker::new(text);
+    let span = options.byte_range(text);
+    lint_span(text, span, &line_tracker, config, options)
+}
+
+/// Which checks a pass over a span runs
+/// `ResultCache` caches the paragraph pass and reruns the document pass every time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Scope {
+    /// Every check
+    All,
+    /// Checks whose results only depend on the paragraph they're in
+    Paragraph,
+    /// Checks that read the whole span (see `is_document_wide`)
+    Document,
+}
+
+impl Scope {
+    fn includes(self, check: &Check, config: &Config) -> bool {
+        match self {
+            Scope::All => true,
+            Scope::Paragraph => !is_document_wide(check, config),
+            Scope::Document => is_document_wide(check, config),
+        }
+    }
+}
+
+/// Whether a check's results depend on text outside their paragraph
+/// In "any" mode every dialect check does, since the majority is taken over the span
+fn is_document_wide(check: &Check, config: &Config) -> bool {
+    check.document_wide || (config.dialect == Dialect::Any && check.dialect.is_some())
+}
+
+/// Whether any check in `scope` would run under `config` and `options`
+pub(crate) fn runs_any(scope: Scope, config: &Config, options: &LintOptions) -> bool {
+    checks::get_all_checks().iter().any(|check| {
+        scope.includes(check, config)
+            && options.selects(check.id, config.severity_of(check.id, check.severity))
+            && should_run(check, config)
+    })
+}
+
+/// Lint one span of the text; `line_tracker` covers the full text
+/// Checks see only the span; results are shifted back by its start
+pub(crate) fn lint_span(
+    text: &str,
+    span: Range<usize>,
+    line_tracker: &LineTracker,
+    config: &Config,
+    options: &LintOptions,
+) -> Vec<LintResult> {
+    let results = run_checks(text, span, line_tracker, config, options, Scope::All);
+    finish_results(results, line_tracker, config, options)
+}
+
+/// Run the checks in `scope` over one span, before overlapping results are merged
+pub(crate) fn run_checks(
+    text: &str,
+    span: Range<usize>,
+    line_tracker: &LineTracker,
+    config: &Config,
+    options: &LintOptions,
+    scope: Scope,
+) -> Vec<LintResult> {
+    let mut results = Vec::new();
+    let check_quotes = options.check_quotes.unwrap_or(config.check_quotes);
+    let max_errors = options.max_errors.unwrap_or(config.max_errors);
+
+    let offset = span.start;
+    let text = &text[span];
     let quote_tracker = QuoteTracker::new(text);
+    let protected_tracker = ProtectedSpanTracker::new(text);
+    let allowed = AllowedSpans::new(text, &config.allowlist, config.allow_regexes());
 
     // Get all checks and AC index
     let all_checks = checks::get_all_checks();
     let ac_index = get_ac_index();
+    let selected = |check: &Check| {
+        scope.includes(check, config)
+            && options.selects(check.id, config.severity_of(check.id, check.severity))
+            && should_run(check, config)
+    };
 
     // Step 1: Fast AC scan to find which checks might match
     let mut checks_to_run: HashSet<usize> = HashSet::new();
 
-    // Add pure regex checks (must always run)
-    for &check_idx in &ac_index.pure_regex_checks {
-        if config.is_check_enabled(all_checks[check_idx].id) {
+    // Add checks that can't be pre-filtered (must always run)
+    for &check_idx in &ac_index.unfiltered_checks {
+        if selected(&all_checks[check_idx]) {
             checks_to_run.insert(check_idx);
         }
     }
 
-    // Scan text with Aho-Corasick to find keyword matches
-    let text_lower = text.to_lowercase();
-    for mat in ac_index.ac.find_iter(&text_lower) {
-        let pattern_idx = mat.pattern().as_usize();
-        for &check_idx in &ac_index.pattern_to_checks[pattern_idx] {
-            if config.is_check_enabled(all_checks[check_idx].id) {
+    // One pass per regex set finds which pure-regex checks match at all
+    for group in &ac_index.regex_sets {
+        for pattern_idx in group.set.matches(text).iter() {
+            let check_idx = group.checks[pattern_idx];
+            if selected(&all_checks[check_idx]) {
                 checks_to_run.insert(check_idx);
             }
         }
     }
 
+    // Scan text with Aho-Corasick to find keyword matches
+    let text_lower = text.to_lowercase();
+    for check_idx in ac_index.keyword_checks(&text_lower) {
+        if selected(&all_checks[check_idx]) {
+            checks_to_run.insert(check_idx);
+        }
+    }
+
+    // Dialect hits held back in "any" mode until we know which side the document favors
+    let mut variant_hits: Vec<(Convention, Variant, LintResult)> = Vec::new();
+
     // Step 2: Run only the checks that might have matches
-    for check_idx in checks_to_run {
+    'checks: for check_idx in checks_to_run {
         let check = &all_checks[check_idx];
+        let severity = config.severity_of(check.id, check.severity);
 
-        // Run the check's regex
-        let matches = check.run(text);
+        // Run the check's regex (or custom matcher)
+        let matches = check.find_matches(text, config);
 
-        for (start, end, replacement) in matches {
+        for CheckMatch {
+            start,
+            end,
+            replacement,
+            message,
+            related,
+        } in matches
+        {
             // Skip matches inside quotes if check doesn't allow it
-            if !check.allow_quotes && !config.check_quotes
+            if !check.allow_quotes && !check_quotes
                 && quote_tracker.overlaps_quote(start, end) {
                     continue;
                 }
 
+            // Skip matches inside URLs, paths, identifiers and numbers
+            if !check.allow_protected && protected_tracker.contains(start, end) {
+                continue;
+            }
+
+            // Skip matches inside allowlisted phrases
+            if allowed.suppresses(check.id, start, end) {
+                continue;
+            }
+
             // Convert to line/column
-            let (line, column) = line_tracker.offset_to_position(start);
+            let (line, column) = line_tracker.offset_to_position(start + offset);
+            let (end_line, end_column) = line_tracker.offset_to_position(end + offset);
 
-            results.push(LintResult {
+            let result = LintResult {
                 check: check.id.to_string(),
-                message: check.message.to_string(),
+                message: message.unwrap_or_else(|| check.message.to_string()),
                 line,
                 column,
-                start,
-                end,
-                severity: check.severity.as_str().to_string(),
+                end_line,
+                end_column,
+                start: start + offset,
+                end: end + offset,
+                severity: severity.as_str().to_string(),
                 replacement,
-            });
+                related: related
+                    .into_iter()
+                    .map(|r| r.start + offset..r.end + offset)
+                    .collect(),
+                merged: Vec::new(),
+                utf16_start: None,
+                utf16_end: None,
+                char_start: None,
+                char_end: None,
+            };
 
-            // Check max errors limit
-            if config.max_errors > 0 && results.len() >= config.max_errors {
-                return results;
+            if let (Dialect::Any, Some((convention, variant))) = (config.dialect, check.dialect) {
+                variant_hits.push((convention, variant, result));
+                continue;
+            }
+
+            results.push(result);
+
+            // Check max errors limit (merging may drop results, so only stop early without it)
+            if config.overlap == OverlapPolicy::KeepAll
+                && max_errors > 0
+                && results.len() >= max_errors
+            {
+                break 'checks;
             }
         }
     }
 
+    results.extend(mixed_variant_results(variant_hits));
+    results
+}
+
+/// Merge overlapping results, apply `max_errors`, sort, and add the offsets the
+/// config asks for
+pub(crate) fn finish_results(
+    results: Vec<LintResult>,
+    line_tracker: &LineTracker,
+    config: &Config,
+    options: &LintOptions,
+) -> Vec<LintResult> {
+    let max_errors = options.max_errors.unwrap_or(config.max_errors);
+    let mut results = resolve_overlaps(results, config.overlap);
+    if max_errors > 0 {
+        results.truncate(max_errors);
+    }
+
     // Sort results by position
     results.sort_by(|a, b| a.line.cmp(&b.line).then(a.column.cmp(&b.column)));
 
+    if options.utf16_offsets.unwrap_or(config.utf16_offsets) {
+        for result in &mut results {
+            result.utf16_start = Some(line_tracker.utf16_offset(result.start));
+            result.utf16_end = Some(line_tracker.utf16_offset(result.end));
+        }
+    }
+    if options.char_offsets.unwrap_or(config.char_offsets) {
+        for result in &mut results {
+            result.char_start = Some(line_tracker.char_offset(result.start));
+            result.char_end = Some(line_tracker.char_offset(result.end));
+        }
+    }
+
     results
 }
 
@@ -199,6 +600,8 @@ pub fn lint_existence(
     let mut results = Vec::new();
     let line_tracker = LineTracker::new(text);
     let quote_tracker = QuoteTracker::new(text);
+    let protected_tracker = ProtectedSpanTracker::new(text);
+    let allowed = AllowedSpans::new(text, &config.allowlist, config.allow_regexes());
 
     if !config.is_check_enabled(check_id) {
         return results;
@@ -218,17 +621,36 @@ pub fn lint_existence(
                     continue;
                 }
 
+                // Skip if in a URL, path, identifier or number
+                if protected_tracker.contains(start, end) {
+                    continue;
+                }
+
+                // Skip if allowlisted
+                if allowed.suppresses(check_id, start, end) {
+                    continue;
+                }
+
                 let (line, column) = line_tracker.offset_to_position(start);
+                let (end_line, end_column) = line_tracker.offset_to_position(end);
 
                 results.push(LintResult {
                     check: check_id.to_string(),
                     message: message.to_string(),
                     line,
                     column,
+                    end_line,
+                    end_column,
                     start,
                     end,
                     severity: Severity::Warning.as_str().to_string(),
                     replacement: None,
+                    related: Vec::new(),
+                    merged: Vec::new(),
+                    utf16_start: None,
+                    utf16_end: None,
+                    char_start: None,
+                    char_end: None,
                 });
 
                 if config.max_errors > 0 && results.len() >= config.max_errors {
@@ -256,11 +678,300 @@ mod tests {
     #[test]
     fn test_ac_index_build() {
         let index = get_ac_index();
+        let all_checks = checks::get_all_checks();
+        let pure_regex: Vec<&str> = index
+            .regex_sets
+            .iter()
+            .flat_map(|g| g.checks.iter().map(|&i| all_checks[i].id))
+            .collect();
         println!(
-            "AC index: {} keywords, {} pure regex checks",
+            "AC index: {} keywords, {} pure regex checks in {} sets, {} unfiltered: {:?}",
             index.pattern_to_checks.len(),
-            index.pure_regex_checks.len()
+            pure_regex.len(),
+            index.regex_sets.len(),
+            index.unfiltered_checks.len(),
+            pure_regex
+        );
+        // Every regex check compiles, so only matchers go unfiltered
+        assert!(index
+            .unfiltered_checks
+            .iter()
+            .all(|&i| all_checks[i].matcher.is_some()));
+    }
+
+    #[test]
+    fn test_keyword_scan_finds_overlapping_keywords() {
+        let index = get_ac_index();
+        let all_checks = checks::get_all_checks();
+        for (check_idx, check) in all_checks.iter().enumerate() {
+            if check.matcher.is_some() {
+                continue;
+            }
+            for keyword in extract_keywords(check).iter().flatten() {
+                assert!(
+                    index.keyword_checks(keyword).any(|i| i == check_idx),
+                    "{}: keyword {:?} hidden by a shorter one",
+                    check.id,
+                    keyword
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_keywords() {
+        let keywords = |pattern| extract_keywords(&Check::new("test", "", pattern));
+        assert_eq!(
+            keywords("very unique"),
+            Some(vec!["very unique".to_string()])
+        );
+        assert_eq!(
+            keywords(r"synerg(?:y|ies|istic|ize)"),
+            Some(vec![
+                "synergies".to_string(),
+                "synergistic".to_string(),
+                "synergize".to_string(),
+                "synergy".to_string()
+            ])
+        );
+        assert_eq!(
+            keywords(r"\bbear[- ]like\b"),
+            Some(vec!["bear like".to_string(), "bear-like".to_string()])
+        );
+        // Falls back to suffixes when the prefix is open-ended
+        assert_eq!(
+            keywords(r"\w+ly unique"),
+            Some(vec!["ly unique".to_string()])
+        );
+        assert_eq!(
+            keywords(r"(?-i:Internet)"),
+            Some(vec!["internet".to_string()])
+        );
+        assert_eq!(keywords(r"[!?]{2,}").map(|k| k.len()), Some(4));
+        // No literal factor long enough to be worth scanning for
+        assert_eq!(keywords(r"\w+\s+\w+"), None);
+        assert_eq!(keywords(r"\d+ ?x ?\d+"), None);
+    }
+
+    #[test]
+    fn test_keywords_find_bad_examples() {
+        for check in checks::get_all_checks() {
+            let Some(keywords) = extract_keywords(check) else {
+                continue;
+            };
+            for example in check.bad_examples {
+                let lower = example.to_lowercase();
+                assert!(
+                    keywords.iter().any(|k| lower.contains(k.as_str())),
+                    "{}: keywords {:?} miss {:?}",
+                    check.id,
+                    keywords,
+                    example
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefilter_pattern() {
+        assert_eq!(prefilter_pattern(r"very \w+ly"), r"(?i)very \w+ly");
+        assert_eq!(prefilter_pattern(r"\bfoo\B|bar\b"), r"(?i)foo|bar");
+        // An escaped backslash followed by "b" is not a boundary
+        assert_eq!(prefilter_pattern(r"a\\b"), r"(?i)a\\b");
+    }
+
+    #[test]
+    fn test_regex_sets_find_bad_examples() {
+        let all_checks = checks::get_all_checks();
+        for group in &get_ac_index().regex_sets {
+            for (pattern_idx, &check_idx) in group.checks.iter().enumerate() {
+                let check = &all_checks[check_idx];
+                for example in check.bad_examples {
+                    assert!(
+                        group.set.matches(example).matched(pattern_idx),
+                        "{}: regex set misses {:?}",
+                        check.id,
+                        example
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "all-checks")]
+    #[test]
+    fn test_dialect_flags_opposite_side() {
+        let text = "We walked towards the centre, toward the theater.";
+        let has = |results: &[LintResult], id: &str| results.iter().any(|r| r.check == id);
+
+        let american = lint_text(text, &Config::default());
+        assert!(has(&american, "needless_variants.towards"));
+        assert!(!has(&american, "dialects.wards.toward"));
+
+        let british = lint_text(text, &Config::with_dialect(Dialect::British));
+        assert!(!has(&british, "needless_variants.towards"));
+        assert!(has(&british, "dialects.wards.toward"));
+        assert!(has(&british, "spelling.consistency.us.11")); // theater
+    }
+
+    #[cfg(feature = "all-checks")]
+    #[test]
+    fn test_dialect_any_flags_minority_side() {
+        let config = Config::with_dialect(Dialect::Any);
+        let is_variant = |r: &&LintResult| {
+            r.check.starts_with("spelling.consistency") || r.check.starts_with("dialects")
+        };
+
+        let consistent = lint_text("The colour of the honour guard.", &config);
+        assert_eq!(consistent.iter().filter(is_variant).count(), 0);
+
+        let mixed = lint_text("The colour and flavour clashed with the color.", &config);
+        let flagged: Vec<_> = mixed.iter().filter(is_variant).collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].replacement.as_deref(), Some("colour"));
+
+        // "towards" is flagged by two checks but is still one British word
+        let wards = lint_text("Towards the sea, toward the hills, toward home.", &config);
+        let flagged: Vec<_> = wards.iter().filter(|r| r.check.contains("wards")).collect();
+        assert!(!flagged.is_empty());
+        assert!(flagged.iter().all(|r| r.start == 0));
+    }
+
+    #[cfg(feature = "all-checks")]
+    #[test]
+    fn test_overlap_resolution() {
+        let text = "They sat in close proximity. The affect was clear.";
+        let count = |results: &[LintResult], start: usize| {
+            results.iter().filter(|r| r.start == start).count()
+        };
+
+        let mut config = Config::default();
+        config.overlap = OverlapPolicy::KeepAll;
+        let all = lint_text(text, &config);
+        assert!(count(&all, 12) >= 2);
+
+        let merged = lint_text(text, &Config::default());
+        assert_eq!(count(&merged, 12), 1);
+        let proximity = merged.iter().find(|r| r.start == 12).unwrap();
+        assert_eq!(proximity.check, "misc.illogic.close_proximity");
+        assert!(proximity.merged.contains(&"redundancy.atd.30".to_string()));
+
+        let affect = merged.iter().find(|r| r.start == 29).unwrap();
+        assert_eq!(affect.check, "spelling.affect_effect");
+        assert_eq!(affect.merged, ["misc.affect_effect"]);
+
+        // Sentence-length results don't swallow the phrases inside them
+        config.overlap = OverlapPolicy::MostSpecific;
+        config.length.max_sentence_words = 2;
+        let with_length = lint_text(text, &config);
+        assert!(with_length.iter().any(|r| r.check == "length.sentence"));
+        assert_eq!(count(&with_length, 12), 1);
+
+        // A repeated word is one result, whichever checks flag it
+        let to_to = lint_text("I went to to the store.", &Config::default());
+        assert_eq!(to_to.iter().filter(|r| r.start == 7).count(), 1);
+
+        // Each check ID is listed once, and never as the result's own check
+        let unique = lint_text("It was very unique.", &Config::default());
+        let very_unique = unique.iter().find(|r| r.start == 7).unwrap();
+        let mut merged = very_unique.merged.clone();
+        merged.sort();
+        merged.dedup();
+        assert_eq!(merged.len(), very_unique.merged.len());
+        assert!(!very_unique.merged.contains(&very_unique.check));
+    }
+
+    #[cfg(feature = "all-checks")]
+    #[test]
+    fn test_lint_with_options() {
+        let text = "This is very unique... It was very bad, at 5 o'clock PM.";
+        let config = Config::default();
+        let all = lint_text(text, &config);
+
+        let typography = lint_with_options(text, &config, &LintOptions::category("typography"));
+        assert!(!typography.is_empty());
+        assert!(typography.iter().all(|r| r.category() == "typography"));
+
+        let errors = lint_with_options(text, &config, &LintOptions::min_severity(Severity::Error));
+        assert!(!errors.is_empty());
+        assert!(errors.iter().all(LintResult::is_error));
+
+        let options = LintOptions {
+            max_errors: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(lint_with_options(text, &config, &options).len(), 1);
+
+        // Offsets and positions stay relative to the full text
+        let second = text.find("It was").unwrap();
+        let options = LintOptions {
+            range: Some(second..text.len()),
+            ..Default::default()
+        };
+        let ranged = lint_with_options(text, &config, &options);
+        let expected: Vec<LintResult> = all.into_iter().filter(|r| r.start >= second).collect();
+        assert!(!ranged.is_empty());
+        assert_eq!(ranged, expected);
+    }
+
+    #[cfg(feature = "length")]
+    #[test]
+    fn test_end_position_of_multiline_match() {
+        use crate::position::PositionEncoding;
+
+        let text = format!(
+            "{}\n{}end.\n",
+            "word ".repeat(30).trim_end(),
+            "more ".repeat(20)
         );
+        let results =
+            lint_with_options(&text, &Config::default(), &LintOptions::category("length"));
+        let sentence = results
+            .iter()
+            .find(|r| r.check == "length.sentence")
+            .unwrap();
+
+        assert_eq!((sentence.line, sentence.column), (1, 1));
+        assert_eq!((sentence.end_line, sentence.end_column), (2, 105));
+
+        let tracker = LineTracker::new(&text);
+        let range = sentence.lsp_range(&tracker, PositionEncoding::Utf16);
+        assert_eq!((range.start.line, range.start.character), (0, 0));
+        assert_eq!((range.end.line, range.end.character), (1, 104));
+    }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_utf16_and_char_offsets() {
+        let text = "日本 👋 This is very bad.";
+        let very = |results: &[LintResult]| {
+            results
+                .iter()
+                .find(|r| r.check == "weasel_words.very")
+                .cloned()
+                .unwrap()
+        };
+
+        let plain = very(&lint_text(text, &Config::default()));
+        assert_eq!(plain.utf16_start, None);
+        assert_eq!(plain.char_start, None);
+
+        let mut config = Config::default();
+        config.utf16_offsets = true;
+        let result = very(&lint_text(text, &config));
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        let span = &utf16[result.utf16_start.unwrap()..result.utf16_end.unwrap()];
+        assert_eq!(String::from_utf16(span).unwrap(), "very");
+        assert_eq!(result.char_start, None);
+
+        let options = LintOptions {
+            char_offsets: Some(true),
+            ..Default::default()
+        };
+        let result = very(&lint_with_options(text, &Config::default(), &options));
+        let chars: Vec<char> = text.chars().collect();
+        let span = &chars[result.char_start.unwrap()..result.char_end.unwrap()];
+        assert_eq!(span.iter().collect::<String>(), "very");
     }
 
     #[test]
@@ -278,4 +989,65 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_apostrophes_do_not_hide_results() {
+        let mut config = Config::default();
+        config.check_quotes = false;
+
+        let results = lint_text("Don't do it, it's very fine.", &config);
+        assert!(results.iter().any(|r| r.check == "weasel_words.very"));
+    }
+
+    #[cfg(feature = "typography")]
+    #[test]
+    fn test_protected_spans_suppress_checks() {
+        let config = Config::default();
+        let typography = LintOptions::category("typography");
+        let checks = |text: &str| -> Vec<String> {
+            lint_with_options(text, &config, &typography)
+                .into_iter()
+                .map(|r| r.check)
+                .collect()
+        };
+
+        assert!(checks("Pages 10-20.").contains(&"typography.dashes.en_dash_range".to_string()));
+        assert!(checks("Due 2026-10-17, call 555-123-4567.").is_empty());
+        assert!(checks("Set the mask to 0x10.").is_empty());
+    }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_allowlist_suppresses_results() {
+        use crate::allowlist::AllowEntry;
+
+        let text = "Very Good Ventures shipped a very rough draft.";
+        let very_count = |config: &Config| {
+            lint_text(text, config)
+                .iter()
+                .filter(|r| r.check == "weasel_words.very")
+                .count()
+        };
+
+        let mut config = Config::default();
+        assert_eq!(very_count(&config), 2);
+
+        config.allow(AllowEntry::phrase("Very Good Ventures"));
+        assert_eq!(very_count(&config), 1);
+
+        assert_eq!(
+            config.load_allowlist("[weasel_words.very] very rough\nvery rough\n"),
+            Ok(2)
+        );
+        assert_eq!(very_count(&config), 0);
+        // Listed entries aren't added twice, and a bad regex rejects the whole list
+        assert_eq!(config.load_allowlist("very rough\n"), Ok(0));
+        assert!(config.load_allowlist("shipped\n/(/\n").is_err());
+        assert_eq!(config.allowlist.len(), 3);
+
+        // Scoped entries leave other checks alone
+        config.allowlist = vec![AllowEntry::regex("(?i)very").for_check("hedging")];
+        assert_eq!(very_count(&config), 2);
+    }
 }
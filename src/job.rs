@@ -0,0 +1,278 @@
+//! Resumable linting for large documents
+//!
+//! A `LintJob` splits its text into chunks at paragraph breaks and lints a few
+//! chunks per step, so a caller on a UI thread can interleave linting with other
+//! work. The JavaScript methods (`step`, `on_progress`, ...) are in `js.rs`.
+
+use crate::engine;
+use crate::position::{LineIndex, LineTracker, QuoteTracker};
+use crate::tokenize::prose_paragraphs;
+use crate::{Config, LintOptions, LintResult};
+use std::ops::Range;
+use wasm_bindgen::prelude::*;
+
+/// Chunks are grown paragraph by paragraph up to about this many bytes
+/// A single longer paragraph still makes up one chunk
+const CHUNK_BYTES: usize = 4096;
+
+/// A lint of one text, run a chunk at a time
+///
+/// Checks only see one chunk at a time, so results that depend on the whole
+/// document (crutch words, echo words near a chunk break, the "any" dialect
+/// mode's majority) can differ from `Linter::check`.
+#[wasm_bindgen]
+pub struct LintJob {
+    text: String,
+    config: Config,
+    line_index: LineIndex,
+    chunks: Vec<Range<usize>>,
+    next_chunk: usize,
+    results: Vec<LintResult>,
+    cancelled: bool,
+    /// JS callback receiving a progress report after each step
+    pub(crate) on_progress: Option<js_sys::Function>,
+}
+
+impl LintJob {
+    /// Create a job that lints `text` with `config`
+    pub fn new(text: impl Into<String>, config: Config) -> Self {
+        let text = text.into();
+        Self {
+            line_index: LineIndex::new(&text),
+            chunks: paragraph_chunks(&text, 0..text.len(), CHUNK_BYTES),
+            text,
+            config,
+            next_chunk: 0,
+            results: Vec::new(),
+            cancelled: false,
+            on_progress: None,
+        }
+    }
+
+    /// Lint chunks until `budget_ms` milliseconds have passed and return the new results
+    /// Always lints at least one chunk, so every step makes progress
+    pub fn advance(&mut self, budget_ms: f64) -> &[LintResult] {
+        let deadline = now_ms() + budget_ms;
+        self.run(|_| now_ms() < deadline)
+    }
+
+    /// Lint up to `chunks` chunks (at least one) and return the new results
+    pub fn advance_chunks(&mut self, chunks: usize) -> &[LintResult] {
+        self.run(|done| done < chunks)
+    }
+
+    /// Every result found so far, in document order
+    pub fn results(&self) -> &[LintResult] {
+        &self.results
+    }
+
+    /// Consume the job and return its results
+    pub fn into_results(self) -> Vec<LintResult> {
+        self.results
+    }
+
+    /// Bytes of text linted so far
+    pub fn processed_bytes(&self) -> usize {
+        if self.next_chunk == self.chunks.len() {
+            self.text.len()
+        } else {
+            self.chunks[self.next_chunk].start
+        }
+    }
+
+    /// Total bytes of text
+    pub fn total_bytes(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Lint chunks while `more(chunks_done_this_step)` allows; returns the new results
+    fn run(&mut self, mut more: impl FnMut(usize) -> bool) -> &[LintResult] {
+        let first_new = self.results.len();
+        let options = LintOptions::default();
+        let line_tracker = LineTracker::with_index(&self.text, &self.line_index);
+        let mut done = 0;
+
+        while !self.is_done() && (done == 0 || more(done)) {
+            let span = self.chunks[self.next_chunk].clone();
+            let chunk_results =
+                engine::lint_span(&self.text, span, &line_tracker, &self.config, &options);
+            self.results.extend(chunk_results);
+            self.next_chunk += 1;
+            done += 1;
+
+            let max_errors = self.config.max_errors;
+            if max_errors > 0 && self.results.len() >= max_errors {
+                self.results.truncate(max_errors);
+                self.next_chunk = self.chunks.len();
+            }
+        }
+
+        &self.results[first_new.min(self.results.len())..]
+    }
+}
+
+#[wasm_bindgen]
+impl LintJob {
+    /// Stop the job; later steps do nothing
+    #[wasm_bindgen]
+    pub fn cancel(&mut self) {
+        self.cancelled = true;
+    }
+
+    /// True once the job was cancelled
+    #[wasm_bindgen]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// True once every chunk has been linted or the job was cancelled
+    #[wasm_bindgen]
+    pub fn is_done(&self) -> bool {
+        self.cancelled || self.next_chunk == self.chunks.len()
+    }
+
+    /// Share of the text linted so far, from 0 to 1
+    #[wasm_bindgen]
+    pub fn progress(&self) -> f64 {
+        if self.text.is_empty() {
+            return 1.0;
+        }
+        self.processed_bytes() as f64 / self.text.len() as f64
+    }
+}
+
+/// Split `span` where a prose paragraph (see `prose_paragraphs`) starts after a
+/// blank line outside any quotation, so each chunk lints as it does in place
+/// Chunks start at the beginning of a line. Paragraphs are grouped until a chunk
+/// has at least `min_bytes` (0 = one per paragraph)
+pub(crate) fn paragraph_chunks(
+    text: &str,
+    span: Range<usize>,
+    min_bytes: usize,
+) -> Vec<Range<usize>> {
+    let base = span.start;
+    let text = &text[span];
+    let quotes = QuoteTracker::new(text);
+    let after_blank_line = |line_start: usize| {
+        text[..line_start].strip_suffix('\n').is_some_and(|rest| {
+            let previous = &rest[rest.rfind('\n').map_or(0, |i| i + 1)..];
+            previous.trim().is_empty()
+        })
+    };
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for paragraph in prose_paragraphs(text) {
+        let line_start = text[..paragraph.start].rfind('\n').map_or(0, |i| i + 1);
+        if line_start > start
+            && line_start - start >= min_bytes
+            && after_blank_line(line_start)
+            && !quotes.is_in_quote(line_start - 1)
+        {
+            chunks.push(base + start..base + line_start);
+            start = line_start;
+        }
+    }
+    if start < text.len() {
+        chunks.push(base + start..base + text.len());
+    }
+    chunks
+}
+
+/// Milliseconds since an arbitrary point, for step budgets
+fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::sync::OnceLock;
+        use std::time::Instant;
+
+        static START: OnceLock<Instant> = OnceLock::new();
+        START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraph_chunks() {
+        let chunks = |text: &str, min_bytes| paragraph_chunks(text, 0..text.len(), min_bytes);
+        assert!(chunks("", CHUNK_BYTES).is_empty());
+        assert_eq!(chunks("One paragraph.", CHUNK_BYTES), vec![0..14]);
+        assert_eq!(
+            chunks("One.\n\nTwo.\n\n\nThree.", 0),
+            vec![0..6, 6..13, 13..19]
+        );
+
+        let paragraph = "word ".repeat(1000);
+        let text = format!("{}\n\n{}\r\n\r\n{}", paragraph, paragraph, paragraph);
+        let grouped = chunks(&text, CHUNK_BYTES);
+        assert_eq!(grouped.len(), 3);
+        assert_eq!(grouped[0].end, paragraph.len() + 2);
+        assert_eq!(grouped[1].end, 2 * paragraph.len() + 6);
+        assert_eq!(grouped[2].end, text.len());
+
+        let text = "One.\n\n```\na\n\nb\n```\n\n\u{201C}Two,\n\n\u{201C}three.\u{201D}\n\nFour.\n";
+        let split: Vec<&str> = chunks(text, 0)
+            .into_iter()
+            .map(|chunk| &text[chunk])
+            .collect();
+        // Fenced code stays with the paragraph before it, and a quotation that
+        // continues into the next paragraph isn't split
+        assert_eq!(
+            split,
+            vec![
+                "One.\n\n```\na\n\nb\n```\n\n",
+                "\u{201C}Two,\n\n\u{201C}three.\u{201D}\n\n",
+                "Four.\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_job_matches_full_lint() {
+        let paragraph = "The results were very unique... We tried to utilize it.\n\n";
+        let text = paragraph.repeat(200);
+        // Crutch words are counted over the whole document
+        let mut config = Config::default();
+        config.disable("repetition");
+        let expected = engine::lint_text(&text, &config);
+
+        let mut job = LintJob::new(text.as_str(), config);
+        let mut steps = 0;
+        while !job.is_done() {
+            job.advance_chunks(1);
+            steps += 1;
+        }
+        assert!(steps > 1);
+        assert_eq!(job.progress(), 1.0);
+        assert_eq!(job.into_results(), expected);
+    }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_job_cancel_and_max_errors() {
+        let text = "It was very bad.\n\n".repeat(1000);
+
+        let mut job = LintJob::new(text.as_str(), Config::default());
+        job.advance_chunks(1);
+        let partial = job.results().len();
+        assert!(job.progress() > 0.0 && job.progress() < 1.0);
+        job.cancel();
+        assert!(job.is_done());
+        assert!(job.advance(1000.0).is_empty());
+        assert_eq!(job.results().len(), partial);
+
+        let mut config = Config::default();
+        config.max_errors = 3;
+        let mut job = LintJob::new(text.as_str(), config);
+        job.advance(f64::INFINITY);
+        assert!(job.is_done());
+        assert_eq!(job.results().len(), 3);
+    }
+}
@@ -0,0 +1,665 @@
+//! Typed JavaScript API
+//!
+//! The string methods on `Proselint` (`lint`, `lint_batch`, ...) return JSON and
+//! report failures as `{"error": ...}` objects. The methods here return plain JS
+//! objects built with `serde-wasm-bindgen` and throw `Error`s whose `name` tells
+//! the failure kind apart. Both layers share the validation below, so they
+//! reject the same input with the same message.
+
+use crate::baseline::{self, Baseline, BaselineMatcher};
+use crate::cache::{CacheStats, ResultCache};
+use crate::checks;
+use crate::config::{Config, LintOptions};
+use crate::diff::{self, ChangedLines};
+use crate::engine;
+use crate::position::{LineTracker, LspRange, PositionEncoding};
+use crate::{lock, LintJob, LintResult, Proselint, MAX_BATCH_SIZE, MAX_TEXT_SIZE};
+use serde::Serialize;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Why a WASM API call failed
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ApiError {
+    /// Text or batch too large, or malformed input
+    Input(String),
+    /// Configuration that doesn't deserialize
+    Config(String),
+    /// Check ID that isn't in the registry
+    UnknownCheck(String),
+    /// Results that couldn't be converted for JavaScript
+    Serialization(String),
+}
+
+impl ApiError {
+    /// `name` of the thrown JS `Error`
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            ApiError::Input(_) => "InputError",
+            ApiError::Config(_) => "ConfigError",
+            ApiError::UnknownCheck(_) => "UnknownCheckError",
+            ApiError::Serialization(_) => "SerializationError",
+        }
+    }
+
+    pub(crate) fn message(&self) -> &str {
+        match self {
+            ApiError::Input(message)
+            | ApiError::Config(message)
+            | ApiError::UnknownCheck(message)
+            | ApiError::Serialization(message) => message,
+        }
+    }
+
+    /// The error as returned by the string API: `{"error": "..."}`
+    pub(crate) fn to_json(&self) -> String {
+        serde_json::json!({ "error": self.message() }).to_string()
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name(), self.message())
+    }
+}
+
+impl From<ApiError> for JsValue {
+    fn from(error: ApiError) -> Self {
+        let js_error = js_sys::Error::new(error.message());
+        js_error.set_name(error.name());
+        js_error.into()
+    }
+}
+
+/// Reject text over `MAX_TEXT_SIZE`
+pub(crate) fn validate_text(text: &str) -> Result<(), ApiError> {
+    if text.len() > MAX_TEXT_SIZE {
+        return Err(ApiError::Input(format!(
+            "Text too large: {} bytes (max {} bytes)",
+            text.len(),
+            MAX_TEXT_SIZE
+        )));
+    }
+    Ok(())
+}
+
+/// Reject batches over `MAX_BATCH_SIZE` texts or with any text over `MAX_TEXT_SIZE`
+pub(crate) fn validate_batch(texts: &[String]) -> Result<(), ApiError> {
+    if texts.len() > MAX_BATCH_SIZE {
+        return Err(ApiError::Input(format!(
+            "Batch too large: {} texts (max {} texts)",
+            texts.len(),
+            MAX_BATCH_SIZE
+        )));
+    }
+
+    for (idx, text) in texts.iter().enumerate() {
+        if text.len() > MAX_TEXT_SIZE {
+            return Err(ApiError::Input(format!(
+                "Text {} too large: {} bytes (max {} bytes)",
+                idx,
+                text.len(),
+                MAX_TEXT_SIZE
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reject oversized text and ranges outside it
+pub(crate) fn validate_options(text: &str, options: &LintOptions) -> Result<(), ApiError> {
+    validate_text(text)?;
+    if let Some(range) = &options.range {
+        if range.start > range.end || range.end > text.len() {
+            return Err(ApiError::Input(format!(
+                "Range {}..{} is out of bounds for {} bytes",
+                range.start,
+                range.end,
+                text.len()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Convert a value to a JS object with the same shape as its JSON form
+/// (`null` for `None`, plain objects rather than `Map`s)
+fn to_js<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, ApiError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| ApiError::Serialization(format!("Failed to serialize results: {}", e)))
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &'static str = r#"
+export type Severity = "error" | "warning" | "suggestion";
+export type Dialect = "american" | "british" | "canadian" | "australian" | "any";
+export type OverlapPolicy = "keep_all" | "most_specific" | "highest_severity";
+export type WindowUnit = "words" | "sentences";
+/** A phrase for every check, or a phrase or regex scoped to a check ID or category */
+export type AllowEntry = string | { phrase: string; check?: string } | { regex: string; check?: string };
+export type PositionEncoding = "utf-8" | "utf-16" | "utf-32";
+
+export interface Span {
+  start: number;
+  end: number;
+}
+
+export interface LintResult {
+  check: string;
+  message: string;
+  /** 1-indexed line */
+  line: number;
+  /** 1-indexed column, in characters */
+  column: number;
+  /** 1-indexed line of the match end */
+  end_line: number;
+  /** 1-indexed column of the match end (exclusive), in characters */
+  end_column: number;
+  /** Byte offset of the match start */
+  start: number;
+  /** Byte offset of the match end */
+  end: number;
+  severity: Severity;
+  replacement: string | null;
+  related?: Span[];
+  merged?: string[];
+  /** Start offset in UTF-16 code units, as JS strings index (with `utf16_offsets`) */
+  utf16_start?: number;
+  /** End offset in UTF-16 code units (with `utf16_offsets`) */
+  utf16_end?: number;
+  /** Start offset in characters (with `char_offsets`) */
+  char_start?: number;
+  /** End offset in characters (with `char_offsets`) */
+  char_end?: number;
+}
+
+/** Zero-based position, as in the Language Server Protocol */
+export interface LspPosition {
+  line: number;
+  /** Offset from the start of the line, in units of the requested encoding */
+  character: number;
+}
+
+export interface LspRange {
+  start: LspPosition;
+  /** Exclusive end */
+  end: LspPosition;
+}
+
+export interface RepetitionConfig {
+  window?: number;
+  unit?: WindowUnit;
+  threshold?: number;
+  crutch_min_count?: number;
+  crutch_min_frequency?: number;
+}
+
+export interface LengthConfig {
+  max_sentence_words?: number;
+  max_paragraph_length?: number;
+  paragraph_unit?: WindowUnit;
+}
+
+export interface Config {
+  max_errors?: number;
+  check_quotes?: boolean;
+  dialect?: Dialect;
+  repetition?: RepetitionConfig;
+  length?: LengthConfig;
+  overlap?: OverlapPolicy;
+  /** Add UTF-16 offsets to results */
+  utf16_offsets?: boolean;
+  /** Add character offsets to results */
+  char_offsets?: boolean;
+  /** Check IDs or ID prefixes mapped to enabled/disabled */
+  checks?: Record<string, boolean>;
+  /** Check IDs or categories mapped to the severity to report them at */
+  severities?: Record<string, Severity>;
+  /** Phrases and patterns whose results are suppressed */
+  allowlist?: AllowEntry[];
+}
+
+export interface LintOptions {
+  /** Only run checks in these categories (or check groups such as "typography.symbols") */
+  categories?: string[];
+  /** Only run checks at least this severe */
+  min_severity?: Severity;
+  /** Overrides the config's max_errors */
+  max_errors?: number;
+  /** Overrides the config's check_quotes */
+  check_quotes?: boolean;
+  /** Overrides the config's utf16_offsets */
+  utf16_offsets?: boolean;
+  /** Overrides the config's char_offsets */
+  char_offsets?: boolean;
+  /** Only lint this byte range; result offsets stay relative to the full text */
+  range?: Span;
+}
+
+export interface CheckInfo {
+  id: string;
+  category: string;
+  severity: Severity;
+  message: string;
+  explanation: string;
+  source?: string;
+  tags: string[];
+  bad_examples: string[];
+  good_examples: string[];
+}
+
+export interface JobProgress {
+  /** Bytes linted so far */
+  processed: number;
+  /** Total bytes of text */
+  total: number;
+  done: boolean;
+  cancelled: boolean;
+  /** Results found in this step */
+  results: LintResult[];
+}
+
+export interface BaselineEntry {
+  check: string;
+  /** Matched text, for reading the baseline file */
+  text: string;
+  count: number;
+}
+
+/** Known results keyed by fingerprint; store it as JSON next to the config */
+export interface Baseline {
+  version: number;
+  entries: Record<string, BaselineEntry>;
+}
+
+export interface BaselineReport {
+  /** Results not in the baseline */
+  results: LintResult[];
+  /** Results hidden because the baseline has them */
+  matched: number;
+  /** Baseline results that no longer occur */
+  stale: number;
+}
+
+export interface CacheStats {
+  /** Paragraphs served from the cache */
+  hits: number;
+  /** Paragraphs that had to be linted */
+  misses: number;
+  /** Entries dropped to stay within the memory budget */
+  evictions: number;
+  entries: number;
+  /** Estimated memory held by the entries */
+  bytes: number;
+  max_bytes: number;
+  /** hits / (hits + misses) */
+  hit_rate: number;
+}
+
+export interface CrutchWord {
+  word: string;
+  count: number;
+  frequency: number;
+  occurrences: Span[];
+}
+"#;
+
+/// Cache statistics with the hit rate worked out for JavaScript
+#[derive(Serialize)]
+struct CacheReport {
+    #[serde(flatten)]
+    stats: CacheStats,
+    hit_rate: f64,
+}
+
+/// Progress report passed to a `LintJob`'s callback after each step
+#[derive(Serialize)]
+struct JobProgress<'a> {
+    processed: usize,
+    total: usize,
+    done: bool,
+    cancelled: bool,
+    results: &'a [LintResult],
+}
+
+#[wasm_bindgen]
+impl Proselint {
+    /// Create a new Proselint instance from a config object
+    /// Throws a `ConfigError` if the object doesn't match `Config`
+    #[wasm_bindgen]
+    pub fn from_config(
+        #[wasm_bindgen(unchecked_param_type = "Config")] config: JsValue,
+    ) -> Result<Proselint, JsValue> {
+        let config: Config = serde_wasm_bindgen::from_value(config)
+            .map_err(|e| ApiError::Config(format!("Invalid config: {}", e)))?;
+        Ok(Self::from(config))
+    }
+
+    /// The current configuration (accepted by `from_config`)
+    #[wasm_bindgen(unchecked_return_type = "Config")]
+    pub fn config(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js(&self.config)?)
+    }
+
+    /// Hit-rate statistics of the cache, or `undefined` if `enable_cache` wasn't called
+    #[wasm_bindgen(unchecked_return_type = "CacheStats | undefined")]
+    pub fn cache_stats(&self) -> Result<JsValue, JsValue> {
+        let Some(stats) = lock(&self.cache).as_ref().map(ResultCache::stats) else {
+            return Ok(JsValue::UNDEFINED);
+        };
+        let report = CacheReport {
+            stats,
+            hit_rate: stats.hit_rate(),
+        };
+        Ok(to_js(&report)?)
+    }
+
+    /// Lint the provided text and return the results as objects
+    /// Throws an `InputError` if the text is too large
+    #[wasm_bindgen(unchecked_return_type = "LintResult[]")]
+    pub fn check(&self, text: &str) -> Result<JsValue, JsValue> {
+        validate_text(text)?;
+        let results = self.lint_cached(text);
+        Ok(to_js(&results)?)
+    }
+
+    /// Lint the provided text with per-call options layered over the config
+    /// Throws an `InputError` if the options are invalid or the text is too large
+    #[wasm_bindgen(unchecked_return_type = "LintResult[]")]
+    pub fn check_with_options(
+        &self,
+        text: &str,
+        #[wasm_bindgen(unchecked_param_type = "LintOptions")] options: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let options: LintOptions = serde_wasm_bindgen::from_value(options)
+            .map_err(|e| ApiError::Input(format!("Invalid lint options: {}", e)))?;
+        validate_options(text, &options)?;
+        let results = engine::lint_with_options(text, &self.config, &options);
+        Ok(to_js(&results)?)
+    }
+
+    /// Start a resumable lint of a large text; drive it with `LintJob.step`
+    /// Throws an `InputError` if the text is too large
+    #[wasm_bindgen]
+    pub fn lint_job(&self, text: String) -> Result<LintJob, JsValue> {
+        validate_text(&text)?;
+        Ok(LintJob::new(text, self.config.clone()))
+    }
+
+    /// Lint the new version of a text and return only results touching lines the
+    /// unified `diff` adds; `path` picks one file from a diff covering several
+    /// Throws an `InputError` for a malformed diff, a path the diff doesn't cover, or oversized text
+    #[wasm_bindgen(unchecked_return_type = "LintResult[]")]
+    pub fn check_diff(
+        &self,
+        text: &str,
+        diff: &str,
+        path: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        validate_text(text)?;
+        let changed = match path {
+            Some(path) => diff::parse_unified_diff(diff)
+                .map_err(ApiError::Input)?
+                .into_iter()
+                .find(|file| file.path == path)
+                .map(|file| file.lines)
+                .ok_or_else(|| ApiError::Input(format!("Diff has no changes to {}", path)))?,
+            None => ChangedLines::from_unified_diff(diff).map_err(ApiError::Input)?,
+        };
+        Ok(to_js(&diff::lint_changed(
+            text,
+            &self.config,
+            &changed,
+            lock(&self.cache).as_mut(),
+        ))?)
+    }
+
+    /// Lint `new_text` and return only results touching lines changed from `old_text`
+    /// Throws an `InputError` if either text is too large
+    #[wasm_bindgen(unchecked_return_type = "LintResult[]")]
+    pub fn check_changes(&self, old_text: &str, new_text: &str) -> Result<JsValue, JsValue> {
+        validate_text(old_text)?;
+        validate_text(new_text)?;
+        let changed = ChangedLines::between(old_text, new_text);
+        Ok(to_js(&diff::lint_changed(
+            new_text,
+            &self.config,
+            &changed,
+            lock(&self.cache).as_mut(),
+        ))?)
+    }
+
+    /// Record the current results of each text in a new baseline
+    /// Throws an `InputError` if the batch or any text is too large
+    #[wasm_bindgen(unchecked_return_type = "Baseline")]
+    pub fn create_baseline(&self, texts: Vec<String>) -> Result<JsValue, JsValue> {
+        validate_batch(&texts)?;
+        let baseline = baseline::lint_baseline(texts.iter().map(String::as_str), &self.config);
+        Ok(to_js(&baseline)?)
+    }
+
+    /// Lint the provided text and report only the results not in `baseline`
+    /// For many documents, filter each one's results through a `BaselineMatcher`
+    /// Throws an `InputError` if the baseline is malformed or the text is too large
+    #[wasm_bindgen(unchecked_return_type = "BaselineReport")]
+    pub fn check_new(
+        &self,
+        text: &str,
+        #[wasm_bindgen(unchecked_param_type = "Baseline")] baseline: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        validate_text(text)?;
+        let baseline = baseline_from_js(baseline)?;
+        Ok(to_js(&baseline::lint_new(text, &self.config, &baseline))?)
+    }
+
+    /// Lint multiple texts in a single call and return one result array per text
+    /// Throws an `InputError` if the batch or any text is too large
+    #[wasm_bindgen(unchecked_return_type = "LintResult[][]")]
+    pub fn check_batch(&self, texts: Vec<String>) -> Result<JsValue, JsValue> {
+        validate_batch(&texts)?;
+        let results: Vec<Vec<LintResult>> =
+            texts.iter().map(|text| self.lint_cached(text)).collect();
+        Ok(to_js(&results)?)
+    }
+
+    /// Report words overused across the whole text
+    /// Throws an `InputError` if the text is too large
+    #[cfg(feature = "repetition")]
+    #[wasm_bindgen(unchecked_return_type = "CrutchWord[]")]
+    pub fn find_crutch_words(&self, text: &str) -> Result<JsValue, JsValue> {
+        validate_text(text)?;
+        let report = checks::repetition::crutch_words(text, &self.config);
+        Ok(to_js(&report)?)
+    }
+
+    /// Zero-based LSP ranges for results from linting `text`, one per result
+    /// `encoding` defaults to "utf-16"; throws an `InputError` for malformed results or encodings
+    #[wasm_bindgen(unchecked_return_type = "LspRange[]")]
+    pub fn lsp_ranges(
+        text: &str,
+        #[wasm_bindgen(unchecked_param_type = "LintResult[]")] results: JsValue,
+        #[wasm_bindgen(unchecked_param_type = "PositionEncoding | undefined")] encoding: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        validate_text(text)?;
+        let results: Vec<LintResult> = serde_wasm_bindgen::from_value(results)
+            .map_err(|e| ApiError::Input(format!("Invalid results: {}", e)))?;
+        let encoding: PositionEncoding = if encoding.is_undefined() {
+            PositionEncoding::default()
+        } else {
+            serde_wasm_bindgen::from_value(encoding)
+                .map_err(|e| ApiError::Input(format!("Invalid position encoding: {}", e)))?
+        };
+
+        let tracker = LineTracker::new(text);
+        let ranges: Vec<LspRange> = results
+            .iter()
+            .map(|result| result.lsp_range(&tracker, encoding))
+            .collect();
+        Ok(to_js(&ranges)?)
+    }
+
+    /// IDs of every available check
+    #[wasm_bindgen]
+    pub fn check_ids() -> Vec<String> {
+        checks::get_all_check_ids()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Describe a check
+    /// Throws an `UnknownCheckError` if the check doesn't exist
+    #[wasm_bindgen(unchecked_return_type = "CheckInfo")]
+    pub fn check_info(id: &str) -> Result<JsValue, JsValue> {
+        let info = checks::describe_check(id)
+            .ok_or_else(|| ApiError::UnknownCheck(format!("Unknown check: {}", id)))?;
+        Ok(to_js(&info)?)
+    }
+
+    /// Metadata for every available check
+    #[wasm_bindgen(unchecked_return_type = "CheckInfo[]")]
+    pub fn check_catalog() -> Result<JsValue, JsValue> {
+        Ok(to_js(&checks::check_catalog())?)
+    }
+}
+
+#[wasm_bindgen]
+impl LintJob {
+    /// Call `callback` with a `JobProgress` after each step
+    #[wasm_bindgen]
+    pub fn on_progress(
+        &mut self,
+        #[wasm_bindgen(unchecked_param_type = "(progress: JobProgress) => void")]
+        callback: js_sys::Function,
+    ) {
+        self.on_progress = Some(callback);
+    }
+
+    /// Lint paragraphs until `budget_ms` milliseconds have passed (at least one chunk)
+    /// Returns true once the job is done; rethrows anything the progress callback throws
+    #[wasm_bindgen]
+    pub fn step(&mut self, budget_ms: f64) -> Result<bool, JsValue> {
+        let first_new = self.results().len();
+        self.advance(budget_ms);
+        self.report(first_new)
+    }
+
+    /// Lint up to `chunks` chunks (at least one), for a work budget instead of a time budget
+    #[wasm_bindgen]
+    pub fn step_chunks(&mut self, chunks: usize) -> Result<bool, JsValue> {
+        let first_new = self.results().len();
+        self.advance_chunks(chunks);
+        self.report(first_new)
+    }
+
+    /// Every result found so far, in document order
+    #[wasm_bindgen(js_name = results, unchecked_return_type = "LintResult[]")]
+    pub fn results_js(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js(self.results())?)
+    }
+}
+
+#[wasm_bindgen]
+impl BaselineMatcher {
+    /// Start comparing results against a baseline from `Proselint.create_baseline`
+    /// Throws an `InputError` if the baseline is malformed
+    #[wasm_bindgen(constructor)]
+    pub fn from_js(
+        #[wasm_bindgen(unchecked_param_type = "Baseline")] baseline: JsValue,
+    ) -> Result<BaselineMatcher, JsValue> {
+        Ok(BaselineMatcher::new(baseline_from_js(baseline)?))
+    }
+
+    /// Keep only the results from linting `text` that aren't in the baseline
+    /// Throws an `InputError` for malformed results
+    #[wasm_bindgen(js_name = filter, unchecked_return_type = "LintResult[]")]
+    pub fn filter_js(
+        &mut self,
+        text: &str,
+        #[wasm_bindgen(unchecked_param_type = "LintResult[]")] results: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let results: Vec<LintResult> = serde_wasm_bindgen::from_value(results)
+            .map_err(|e| ApiError::Input(format!("Invalid results: {}", e)))?;
+        Ok(to_js(&self.filter(text, results))?)
+    }
+
+    /// The baseline without its stale entries, to write back so it shrinks
+    #[wasm_bindgen(js_name = pruned, unchecked_return_type = "Baseline")]
+    pub fn pruned_js(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js(&self.pruned())?)
+    }
+}
+
+/// Deserialize a baseline passed from JavaScript
+fn baseline_from_js(baseline: JsValue) -> Result<Baseline, ApiError> {
+    serde_wasm_bindgen::from_value(baseline)
+        .map_err(|e| ApiError::Input(format!("Invalid baseline: {}", e)))
+}
+
+impl LintJob {
+    /// Pass the results from `first_new` on to the progress callback, if any
+    fn report(&self, first_new: usize) -> Result<bool, JsValue> {
+        if let Some(callback) = &self.on_progress {
+            let progress = JobProgress {
+                processed: self.processed_bytes(),
+                total: self.total_bytes(),
+                done: self.is_done(),
+                cancelled: self.is_cancelled(),
+                results: &self.results()[first_new..],
+            };
+            callback.call1(&JsValue::NULL, &to_js(&progress)?)?;
+        }
+        Ok(self.is_done())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_text() {
+        assert_eq!(validate_text("fine"), Ok(()));
+        let error = validate_text(&"x".repeat(MAX_TEXT_SIZE + 1)).unwrap_err();
+        assert_eq!(error.name(), "InputError");
+        assert!(error.message().starts_with("Text too large"));
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        assert_eq!(validate_batch(&["a".to_string(), "b".to_string()]), Ok(()));
+
+        let too_many = vec![String::new(); MAX_BATCH_SIZE + 1];
+        assert!(validate_batch(&too_many)
+            .unwrap_err()
+            .message()
+            .starts_with("Batch too large"));
+
+        let too_long = vec![String::new(), "x".repeat(MAX_TEXT_SIZE + 1)];
+        assert!(validate_batch(&too_long)
+            .unwrap_err()
+            .message()
+            .starts_with("Text 1 too large"));
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let options = LintOptions {
+            range: Some(2..4),
+            ..Default::default()
+        };
+        assert_eq!(validate_options("text", &options), Ok(()));
+        assert!(validate_options("tex", &options)
+            .unwrap_err()
+            .message()
+            .starts_with("Range 2..4 is out of bounds"));
+    }
+
+    #[test]
+    fn test_error_json_is_escaped() {
+        let error = ApiError::Config(r#"expected "," at line 1"#.to_string());
+        let parsed: serde_json::Value = serde_json::from_str(&error.to_json()).unwrap();
+        assert_eq!(parsed["error"], r#"expected "," at line 1"#);
+    }
+}
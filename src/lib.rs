@@ -24,11 +24,12 @@
 //!
 //! await init();
 //! const linter = new Proselint();
-//! const results = JSON.parse(linter.lint("This is very very important."));
+//! const results = linter.check("This is very very important.");
 //! ```
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use wasm_bindgen::prelude::*;
 
 /// Maximum allowed text size for linting (10 MB)
@@ -38,19 +39,41 @@ pub const MAX_TEXT_SIZE: usize = 10 * 1024 * 1024;
 /// Maximum allowed batch size for lint_batch
 pub const MAX_BATCH_SIZE: usize = 100;
 
+mod allowlist;
+mod baseline;
+mod cache;
 mod check;
 mod checks;
 mod config;
+mod dialect;
+mod diff;
 mod engine;
+mod job;
+mod js;
 mod position;
+// Only the token-based checks need the tokenizer, and each uses part of it
+#[cfg_attr(not(feature = "all-checks"), allow(dead_code))]
+mod tokenize;
 
 // Re-export core types
+pub use allowlist::{AllowEntry, AllowPattern};
+pub use baseline::{
+    fingerprint, Baseline, BaselineEntry, BaselineMatcher, BaselineReport, BASELINE_VERSION,
+};
+pub use cache::{CacheStats, ResultCache, DEFAULT_CACHE_BYTES};
 pub use check::*;
 pub use config::*;
+pub use dialect::*;
+pub use diff::{parse_unified_diff, ChangedLines, FileChanges};
 pub use engine::*;
+pub use job::LintJob;
 pub use position::*;
 
 // Re-export check registry functions for native Rust users
+#[cfg(feature = "repetition")]
+pub use checks::repetition::{crutch_words, CrutchWord};
+pub use checks::{category_description, check_catalog, compiled_categories, describe_check};
+pub use checks::{example_coverage, verify_examples, ExampleCoverage};
 pub use checks::{get_all_check_ids, get_all_checks, get_checks_by_category, validate_all_checks};
 
 /// A single lint result representing a detected issue
@@ -64,6 +87,12 @@ pub struct LintResult {
     pub line: usize,
     /// Column number (1-indexed)
     pub column: usize,
+    /// Line number of the match end (1-indexed)
+    #[serde(default)]
+    pub end_line: usize,
+    /// Column number of the match end (1-indexed, exclusive)
+    #[serde(default)]
+    pub end_column: usize,
     /// Start position in the text (0-indexed byte offset)
     pub start: usize,
     /// End position in the text (0-indexed byte offset)
@@ -72,6 +101,24 @@ pub struct LintResult {
     pub severity: String,
     /// Optional replacement text
     pub replacement: Option<String>,
+    /// Other spans linked to this issue (e.g., earlier occurrences of an echoed word)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<std::ops::Range<usize>>,
+    /// IDs of checks whose overlapping results were merged into this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged: Vec<String>,
+    /// Start position in UTF-16 code units (with `Config::utf16_offsets`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utf16_start: Option<usize>,
+    /// End position in UTF-16 code units (with `Config::utf16_offsets`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utf16_end: Option<usize>,
+    /// Start position in characters (with `Config::char_offsets`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub char_start: Option<usize>,
+    /// End position in characters (with `Config::char_offsets`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub char_end: Option<usize>,
 }
 
 impl LintResult {
@@ -99,6 +146,12 @@ impl LintResult {
     pub fn span(&self) -> std::ops::Range<usize> {
         self.start..self.end
     }
+
+    /// Zero-based LSP range of this issue, with characters counted in `encoding`
+    /// `tracker` must be built from the text that was linted
+    pub fn lsp_range(&self, tracker: &LineTracker, encoding: PositionEncoding) -> LspRange {
+        tracker.lsp_range(self.span(), encoding)
+    }
 }
 
 impl fmt::Display for LintResult {
@@ -141,50 +194,102 @@ impl fmt::Display for LintResult {
 #[derive(Debug, Clone)]
 pub struct Linter {
     config: Config,
+    /// Opt-in result cache used by `check`; clones of the linter share it
+    cache: Option<Arc<Mutex<ResultCache>>>,
 }
 
 impl Linter {
     /// Create a new Linter with default configuration
     pub fn new() -> Self {
-        Self {
-            config: Config::default(),
-        }
+        Self::with_config(Config::default())
     }
 
     /// Create a new Linter with custom configuration
     pub fn with_config(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            cache: None,
+        }
     }
 
     /// Check text and return lint results
+    /// With a cache set, paragraphs linted before are served from it
     pub fn check(&self, text: &str) -> Vec<LintResult> {
-        engine::lint_text(text, &self.config)
+        match &self.cache {
+            Some(cache) => lock(cache).lint(text, &self.config),
+            None => engine::lint_text(text, &self.config),
+        }
+    }
+
+    /// Cache `check` and `check_changed` results per paragraph (see `ResultCache`)
+    pub fn set_cache(&mut self, cache: ResultCache) {
+        self.cache = Some(Arc::new(Mutex::new(cache)));
+    }
+
+    /// Stop caching and return the cache, e.g. to save it
+    pub fn take_cache(&mut self) -> Option<ResultCache> {
+        let cache = self.cache.take()?;
+        Some(match Arc::try_unwrap(cache) {
+            Ok(cache) => cache.into_inner().unwrap_or_else(PoisonError::into_inner),
+            Err(shared) => lock(&shared).clone(),
+        })
+    }
+
+    /// Hit-rate statistics of the cache, if one is set
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| lock(cache).stats())
+    }
+
+    /// Check text with per-call options (categories, severity, limits, range)
+    pub fn check_with_options(&self, text: &str, options: &LintOptions) -> Vec<LintResult> {
+        engine::lint_with_options(text, &self.config, options)
     }
 
     /// Check text and return only errors
     pub fn check_errors(&self, text: &str) -> Vec<LintResult> {
-        self.check(text)
-            .into_iter()
-            .filter(|r| r.is_error())
-            .collect()
+        self.check_with_options(text, &LintOptions::min_severity(Severity::Error))
     }
 
     /// Check text and return only warnings and errors
     pub fn check_warnings(&self, text: &str) -> Vec<LintResult> {
-        self.check(text)
-            .into_iter()
-            .filter(|r| !r.is_suggestion())
-            .collect()
+        self.check_with_options(text, &LintOptions::min_severity(Severity::Warning))
     }
 
     /// Check text and return results for a specific category
+    /// Only the category's checks run
     pub fn check_category(&self, text: &str, category: &str) -> Vec<LintResult> {
-        self.check(text)
+        self.check_with_options(text, &LintOptions::category(category))
             .into_iter()
             .filter(|r| r.category() == category)
             .collect()
     }
 
+    /// Check text and return only the results not in `baseline`, with counts of
+    /// matched and stale baseline entries (see `Baseline`)
+    pub fn check_new(&self, text: &str, baseline: &Baseline) -> BaselineReport {
+        baseline::lint_new(text, &self.config, baseline)
+    }
+
+    /// Check text and return only results touching changed lines (see `ChangedLines`)
+    /// The paragraphs around the changes are linted, so phrases crossing an edit still match
+    pub fn check_changed(&self, text: &str, changed: &ChangedLines) -> Vec<LintResult> {
+        match &self.cache {
+            Some(cache) => diff::lint_changed(text, &self.config, changed, Some(&mut lock(cache))),
+            None => diff::lint_changed(text, &self.config, changed, None),
+        }
+    }
+
+    /// Record the current results of each text in a new baseline
+    /// Every result is recorded, whatever `max_errors` is set to
+    pub fn baseline(&self, texts: &[&str]) -> Baseline {
+        baseline::lint_baseline(texts.iter().copied(), &self.config)
+    }
+
+    /// Start a resumable lint of a large text (see `LintJob`)
+    pub fn lint_job(&self, text: &str) -> LintJob {
+        LintJob::new(text, self.config.clone())
+    }
+
     /// Returns true if the text has any issues
     pub fn has_issues(&self, text: &str) -> bool {
         !self.check(text).is_empty()
@@ -195,6 +300,12 @@ impl Linter {
         self.check(text).len()
     }
 
+    /// Report words overused across the whole text (see `repetition.crutch_word`)
+    #[cfg(feature = "repetition")]
+    pub fn crutch_words(&self, text: &str) -> Vec<CrutchWord> {
+        checks::repetition::crutch_words(text, &self.config)
+    }
+
     /// Get the configuration
     pub fn config(&self) -> &Config {
         &self.config
@@ -210,6 +321,16 @@ impl Linter {
         checks::get_all_check_ids()
     }
 
+    /// Describe a check: category, severity, explanation, source, tags and examples
+    pub fn describe_check(id: &str) -> Option<CheckInfo> {
+        checks::describe_check(id)
+    }
+
+    /// Describe every available check
+    pub fn catalog() -> Vec<CheckInfo> {
+        checks::check_catalog()
+    }
+
     /// Get the library version
     pub fn version() -> &'static str {
         env!("CARGO_PKG_VERSION")
@@ -270,6 +391,11 @@ impl Linter {
     }
 }
 
+/// Lock a cache, recovering it if another thread panicked while linting
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 impl Default for Linter {
     fn default() -> Self {
         Self::new()
@@ -285,6 +411,8 @@ pub fn check_text(text: &str) -> Vec<LintResult> {
 #[wasm_bindgen]
 pub struct Proselint {
     config: Config,
+    /// Opt-in result cache (see `enable_cache`)
+    cache: Mutex<Option<ResultCache>>,
 }
 
 #[wasm_bindgen]
@@ -292,9 +420,7 @@ impl Proselint {
     /// Create a new Proselint instance with default configuration
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self {
-            config: Config::default(),
-        }
+        Self::from(Config::default())
     }
 
     /// Create a new Proselint instance with custom configuration (JSON string)
@@ -302,33 +428,155 @@ impl Proselint {
     pub fn with_config(config_json: &str) -> Result<Proselint, JsValue> {
         let config: Config = serde_json::from_str(config_json)
             .map_err(|e| JsValue::from_str(&format!("Invalid config: {}", e)))?;
-        Ok(Self { config })
+        Ok(Self::from(config))
+    }
+
+    /// Warnings about configured check IDs that match no available check
+    /// Includes IDs in categories compiled out of this build (see `Config::validate`)
+    #[wasm_bindgen]
+    pub fn config_warnings(&self) -> Vec<String> {
+        self.config.validate()
+    }
+
+    /// Enable a check or category (same as `Config::enable`)
+    #[wasm_bindgen]
+    pub fn enable(&mut self, check_id: &str) {
+        self.config.enable(check_id);
+    }
+
+    /// Disable a check or category (same as `Config::disable`)
+    #[wasm_bindgen]
+    pub fn disable(&mut self, check_id: &str) {
+        self.config.disable(check_id);
+    }
+
+    /// Report a check or category at another severity ("error", "warning" or "suggestion")
+    /// Throws an `InputError` for any other severity
+    #[wasm_bindgen]
+    pub fn set_severity(
+        &mut self,
+        check_id: &str,
+        #[wasm_bindgen(unchecked_param_type = "Severity")] severity: &str,
+    ) -> Result<(), JsValue> {
+        let severity: Severity = severity
+            .parse()
+            .map_err(|e: &str| js::ApiError::Input(e.to_string()))?;
+        self.config.set_severity(check_id, severity);
+        Ok(())
+    }
+
+    /// Set the maximum number of results per lint (0 = unlimited)
+    #[wasm_bindgen]
+    pub fn set_max_errors(&mut self, max_errors: usize) {
+        self.config.max_errors = max_errors;
+    }
+
+    /// Set whether checks run inside quoted text
+    #[wasm_bindgen]
+    pub fn set_check_quotes(&mut self, check_quotes: bool) {
+        self.config.check_quotes = check_quotes;
+    }
+
+    /// Suppress results inside a phrase, for every check or just `check_id`
+    /// (a check ID or category), e.g. when a user picks "ignore everywhere"
+    #[wasm_bindgen]
+    pub fn allow(&mut self, phrase: &str, check_id: Option<String>) {
+        let entry = AllowEntry::phrase(phrase);
+        self.config.allow(match check_id {
+            Some(check_id) => entry.for_check(check_id),
+            None => entry,
+        });
+    }
+
+    /// Suppress results inside text matching a regex, for every check or just `check_id`
+    /// Throws an `InputError` if the regex doesn't compile
+    #[wasm_bindgen]
+    pub fn allow_regex(&mut self, pattern: &str, check_id: Option<String>) -> Result<(), JsValue> {
+        let entry = AllowEntry::regex(pattern);
+        entry.validate().map_err(js::ApiError::Input)?;
+        self.config.allow(match check_id {
+            Some(check_id) => entry.for_check(check_id),
+            None => entry,
+        });
+        Ok(())
+    }
+
+    /// Add the entries of a word list (see `AllowEntry::parse_list`) and return how many were new
+    /// Throws an `InputError`, adding nothing, if a regex in the list doesn't compile
+    #[wasm_bindgen]
+    pub fn load_allowlist(&mut self, list: &str) -> Result<usize, JsValue> {
+        Ok(self
+            .config
+            .load_allowlist(list)
+            .map_err(js::ApiError::Input)?)
+    }
+
+    /// Cache results per paragraph, keeping about `max_bytes` (default 16 MB) of them
+    /// Repeated paragraphs and unchanged texts are then served from the cache
+    #[wasm_bindgen]
+    pub fn enable_cache(&mut self, max_bytes: Option<usize>) {
+        let cache = ResultCache::new(max_bytes.unwrap_or(DEFAULT_CACHE_BYTES));
+        self.cache = Mutex::new(Some(cache));
+    }
+
+    /// Stop caching and drop the cached results
+    #[wasm_bindgen]
+    pub fn disable_cache(&mut self) {
+        self.cache = Mutex::new(None);
+    }
+
+    /// Get the current configuration as JSON (accepted by `with_config`)
+    #[wasm_bindgen]
+    pub fn get_config(&self) -> String {
+        serde_json::to_string(&self.config)
+            .unwrap_or_else(|e| format!(r#"{{"error": "Failed to serialize config: {}"}}"#, e))
+    }
+
+    /// Restore the default configuration
+    #[wasm_bindgen]
+    pub fn reset(&mut self) {
+        self.config = Config::default();
     }
 
     /// Lint the provided text and return results as JSON
     /// Returns a JSON array of lint results, or a JSON object with an "error" field if something goes wrong
     #[wasm_bindgen]
     pub fn lint(&self, text: &str) -> String {
-        // Validate text size
-        if text.len() > MAX_TEXT_SIZE {
-            return format!(
-                r#"{{"error": "Text too large: {} bytes (max {} bytes)"}}"#,
-                text.len(),
-                MAX_TEXT_SIZE
-            );
+        if let Err(e) = js::validate_text(text) {
+            return e.to_json();
         }
 
-        let results = engine::lint_text(text, &self.config);
+        let results = self.lint_cached(text);
         serde_json::to_string(&results).unwrap_or_else(|e| {
             // Return error as JSON object instead of empty array
             format!(r#"{{"error": "Failed to serialize results: {}"}}"#, e)
         })
     }
 
+    /// Lint the provided text with per-call options (JSON string) and return results as JSON
+    /// Options: `categories`, `min_severity`, `max_errors`, `check_quotes` and a byte `range`
+    /// Returns a JSON object with an "error" field for invalid options or input
+    #[wasm_bindgen]
+    pub fn lint_with_options(&self, text: &str, options_json: &str) -> String {
+        let options: LintOptions = match serde_json::from_str(options_json) {
+            Ok(options) => options,
+            Err(e) => {
+                return js::ApiError::Input(format!("Invalid lint options: {}", e)).to_json();
+            }
+        };
+        if let Err(e) = js::validate_options(text, &options) {
+            return e.to_json();
+        }
+
+        let results = engine::lint_with_options(text, &self.config, &options);
+        serde_json::to_string(&results)
+            .unwrap_or_else(|e| format!(r#"{{"error": "Failed to serialize results: {}"}}"#, e))
+    }
+
     /// Lint the provided text and return the number of issues found
     #[wasm_bindgen]
     pub fn lint_count(&self, text: &str) -> usize {
-        engine::lint_text(text, &self.config).len()
+        self.lint_cached(text).len()
     }
 
     /// Lint multiple texts in a single call and return results as JSON array of arrays
@@ -339,40 +587,35 @@ impl Proselint {
         let texts: Vec<String> = match serde_json::from_str(texts_json) {
             Ok(t) => t,
             Err(e) => {
-                return format!(r#"{{"error": "Failed to parse input JSON: {}"}}"#, e);
+                return js::ApiError::Input(format!("Failed to parse input JSON: {}", e)).to_json();
             }
         };
 
-        // Validate batch size
-        if texts.len() > MAX_BATCH_SIZE {
-            return format!(
-                r#"{{"error": "Batch too large: {} texts (max {} texts)"}}"#,
-                texts.len(),
-                MAX_BATCH_SIZE
-            );
-        }
-
-        // Validate individual text sizes
-        for (idx, text) in texts.iter().enumerate() {
-            if text.len() > MAX_TEXT_SIZE {
-                return format!(
-                    r#"{{"error": "Text {} too large: {} bytes (max {} bytes)"}}"#,
-                    idx,
-                    text.len(),
-                    MAX_TEXT_SIZE
-                );
-            }
+        if let Err(e) = js::validate_batch(&texts) {
+            return e.to_json();
         }
 
-        let results: Vec<Vec<LintResult>> = texts
-            .iter()
-            .map(|text| engine::lint_text(text, &self.config))
-            .collect();
+        let results: Vec<Vec<LintResult>> =
+            texts.iter().map(|text| self.lint_cached(text)).collect();
 
         serde_json::to_string(&results)
             .unwrap_or_else(|e| format!(r#"{{"error": "Failed to serialize results: {}"}}"#, e))
     }
 
+    /// Report words overused across the whole text and return them as JSON
+    /// Returns a JSON array of crutch words, or a JSON object with an "error" field
+    #[cfg(feature = "repetition")]
+    #[wasm_bindgen]
+    pub fn crutch_words(&self, text: &str) -> String {
+        if let Err(e) = js::validate_text(text) {
+            return e.to_json();
+        }
+
+        let report = checks::repetition::crutch_words(text, &self.config);
+        serde_json::to_string(&report)
+            .unwrap_or_else(|e| format!(r#"{{"error": "Failed to serialize results: {}"}}"#, e))
+    }
+
     /// Get the version of proselint-wasm
     #[wasm_bindgen]
     pub fn version() -> String {
@@ -385,6 +628,23 @@ impl Proselint {
         serde_json::to_string(&checks::get_all_check_ids()).unwrap_or_else(|_| "[]".to_string())
     }
 
+    /// Describe a check and return its metadata as JSON
+    /// Returns a JSON object with an "error" field if the check doesn't exist
+    #[wasm_bindgen]
+    pub fn describe_check(id: &str) -> String {
+        match checks::describe_check(id) {
+            Some(info) => serde_json::to_string(&info)
+                .unwrap_or_else(|e| format!(r#"{{"error": "Failed to serialize check: {}"}}"#, e)),
+            None => serde_json::json!({ "error": format!("Unknown check: {}", id) }).to_string(),
+        }
+    }
+
+    /// Get metadata for every available check as a JSON array
+    #[wasm_bindgen]
+    pub fn catalog() -> String {
+        serde_json::to_string(&checks::check_catalog()).unwrap_or_else(|_| "[]".to_string())
+    }
+
     /// Pre-compile ALL regexes for maximum performance
     /// Call this once during initialization to pay the compilation cost upfront
     #[wasm_bindgen]
@@ -393,6 +653,25 @@ impl Proselint {
     }
 }
 
+impl Proselint {
+    /// Lint with the config, through the cache if one is enabled
+    pub(crate) fn lint_cached(&self, text: &str) -> Vec<LintResult> {
+        match lock(&self.cache).as_mut() {
+            Some(cache) => cache.lint(text, &self.config),
+            None => engine::lint_text(text, &self.config),
+        }
+    }
+}
+
+impl From<Config> for Proselint {
+    fn from(config: Config) -> Self {
+        Self {
+            config,
+            cache: Mutex::new(None),
+        }
+    }
+}
+
 impl Default for Proselint {
     fn default() -> Self {
         Self::new()
@@ -417,6 +696,77 @@ mod tests {
         assert!(!results.is_empty());
     }
 
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_mutable_config() {
+        let text = "This is very bad.";
+        let weasel_results = |proselint: &Proselint| -> Vec<LintResult> {
+            let results: Vec<LintResult> = serde_json::from_str(&proselint.lint(text)).unwrap();
+            results
+                .into_iter()
+                .filter(|r| r.category() == "weasel_words")
+                .collect()
+        };
+
+        let mut proselint = Proselint::new();
+        proselint.disable("weasel_words");
+        assert!(weasel_results(&proselint).is_empty());
+
+        proselint.enable("weasel_words");
+        proselint.set_severity("weasel_words", "error").unwrap();
+        let results = weasel_results(&proselint);
+        assert!(!results.is_empty());
+        assert!(results.iter().all(LintResult::is_error));
+
+        proselint.set_max_errors(1);
+        proselint.set_check_quotes(false);
+        let config: Config = serde_json::from_str(&proselint.get_config()).unwrap();
+        assert_eq!(config.max_errors, 1);
+        assert!(!config.check_quotes);
+        assert_eq!(config.severities["weasel_words"], Severity::Error);
+
+        proselint.reset();
+        assert!(weasel_results(&proselint).iter().all(|r| !r.is_error()));
+    }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_check_category() {
+        let linter = Linter::new();
+        let text = "This is very bad.";
+        let results = linter.check_category(text, "weasel_words");
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|r| r.category() == "weasel_words"));
+        // Only whole category names match
+        assert!(linter.check_category(text, "weasel").is_empty());
+        assert!(linter.check_category(text, "weasel_words.very").is_empty());
+    }
+
+    #[cfg(feature = "weasel_words")]
+    #[test]
+    fn test_check_new_with_baseline() {
+        let old = "It was a very bad day.\n\nThe team was very tired.";
+        let mut config = Config::default();
+        config.max_errors = 1;
+        let linter = Linter::with_config(config);
+        let baseline = linter.baseline(&[old]);
+        assert!(!baseline.is_empty());
+
+        // Unchanged text, moved down the page: nothing new
+        let moved = format!("An introduction.\n\n{}", old);
+        let report = linter.check_new(&moved, &baseline);
+        assert!(report.results.is_empty());
+        assert_eq!(report.stale, 0);
+
+        // One old issue fixed, one new issue added; max_errors counts new results only
+        let edited = "It was a bad day.\n\nThe team was very tired. The plan was very new.";
+        let report = linter.check_new(edited, &baseline);
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(&edited[report.results[0].span()], "very");
+        assert!(report.results[0].start > edited.find("tired").unwrap());
+        assert_eq!(report.stale, 1);
+    }
+
     #[cfg(test)]
     mod property_tests {
         use super::*;
@@ -3,17 +3,61 @@
 //! Handles conversion between byte offsets and line/column positions.
 //! Properly handles UTF-8 multi-byte characters and different line ending styles.
 
-/// Tracks line boundaries for efficient position lookups
-pub struct LineTracker {
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Unit that LSP positions count characters in (LSP's `PositionEncodingKind`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum PositionEncoding {
+    /// Bytes
+    #[serde(rename = "utf-8")]
+    Utf8,
+    /// UTF-16 code units, the LSP default and what JavaScript strings index by
+    #[default]
+    #[serde(rename = "utf-16")]
+    Utf16,
+    /// Unicode scalar values (chars)
+    #[serde(rename = "utf-32")]
+    Utf32,
+}
+
+/// A zero-based line and character position, as in the Language Server Protocol
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub struct LspPosition {
+    /// Line (0-indexed)
+    pub line: usize,
+    /// Offset from the start of the line, in the units of a `PositionEncoding`
+    pub character: usize,
+}
+
+/// A zero-based range with an exclusive end, as in the Language Server Protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct LspRange {
+    /// Position of the first character
+    pub start: LspPosition,
+    /// Position just past the last character
+    pub end: LspPosition,
+}
+
+/// Line boundaries and char/UTF-16 checkpoints for one text
+/// Holds only offsets, so it can be kept next to the text it was built from
+/// and lent to a `LineTracker` with `LineTracker::with_index`
+#[derive(Debug, Clone, Default)]
+pub struct LineIndex {
     /// Cumulative byte offsets at the end of each line
     line_ends: Vec<usize>,
-    /// Original text for UTF-8 character indexing
-    text: String,
+    /// Byte to char/UTF-16 conversion
+    offsets: OffsetIndex,
 }
 
-impl LineTracker {
-    /// Create a new LineTracker from text
-    /// Handles both Unix (\n) and Windows (\r\n) line endings
+impl LineIndex {
+    /// Build the index for `text`
+    /// Handles Unix (\n), Windows (\r\n) and old Mac (\r) line endings
     pub fn new(text: &str) -> Self {
         let mut line_ends = Vec::new();
 
@@ -44,23 +88,101 @@ impl LineTracker {
 
         Self {
             line_ends,
-            text: text.to_string(),
+            offsets: OffsetIndex::new(text),
+        }
+    }
+}
+
+/// Tracks line boundaries for efficient position lookups
+/// Borrows the text; every lookup is a binary search plus a scan of at most
+/// `CHECKPOINT_BYTES` bytes, however long the line
+pub struct LineTracker<'a> {
+    /// Text the index was built from
+    text: &'a str,
+    /// Line ends and offset checkpoints, built here or lent by the caller
+    index: Cow<'a, LineIndex>,
+}
+
+impl<'a> LineTracker<'a> {
+    /// Create a new LineTracker from text
+    /// Handles both Unix (\n) and Windows (\r\n) line endings
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            index: Cow::Owned(LineIndex::new(text)),
+        }
+    }
+
+    /// Create a LineTracker from an index built earlier with `LineIndex::new(text)`
+    pub fn with_index(text: &'a str, index: &'a LineIndex) -> Self {
+        Self {
+            text,
+            index: Cow::Borrowed(index),
         }
     }
 
     /// Convert a byte offset to (line, column), both 1-indexed
     /// Column numbers are character-based (not byte-based) for proper UTF-8 handling
     pub fn offset_to_position(&self, offset: usize) -> (usize, usize) {
+        let (line_idx, line_start_byte, offset) = self.locate(offset);
+
+        // Count characters (not bytes) from line start to offset
+        // This ensures proper column numbers for multi-byte UTF-8 characters
+        let column = self.char_offset(offset) - self.char_offset(line_start_byte);
+
+        // Return 1-indexed values
+        (line_idx + 1, column + 1)
+    }
+
+    /// Convert a byte offset to a zero-based LSP position
+    /// `character` counts code units of the given encoding from the start of the line
+    pub fn lsp_position(&self, offset: usize, encoding: PositionEncoding) -> LspPosition {
+        let (line_idx, line_start_byte, offset) = self.locate(offset);
+
+        let character = match encoding {
+            PositionEncoding::Utf8 => offset - line_start_byte,
+            PositionEncoding::Utf16 => {
+                self.utf16_offset(offset) - self.utf16_offset(line_start_byte)
+            }
+            PositionEncoding::Utf32 => self.char_offset(offset) - self.char_offset(line_start_byte),
+        };
+
+        LspPosition {
+            line: line_idx,
+            character,
+        }
+    }
+
+    /// Convert a byte range to a zero-based LSP range
+    /// The end is exclusive, so a match ending at a line break ends at the start of the next line
+    pub fn lsp_range(&self, span: Range<usize>, encoding: PositionEncoding) -> LspRange {
+        LspRange {
+            start: self.lsp_position(span.start, encoding),
+            end: self.lsp_position(span.end, encoding),
+        }
+    }
+
+    /// Find the line containing a byte offset
+    /// Returns the 0-indexed line, the byte offset where it starts, and the offset
+    /// clamped to the text and rounded down to a char boundary
+    fn locate(&self, offset: usize) -> (usize, usize, usize) {
         // Clamp offset to text length
-        let offset = offset.min(self.text.len());
+        let mut offset = offset.min(self.text.len());
+
+        let line_ends = &self.index.line_ends;
 
         // Handle empty file
-        if self.line_ends.is_empty() {
-            return (1, 1);
+        if line_ends.is_empty() {
+            return (0, 0, 0);
+        }
+
+        // Ensure we don't try to slice in the middle of a UTF-8 character
+        while !self.text.is_char_boundary(offset) {
+            offset -= 1;
         }
 
         // Binary search to find which line contains this offset
-        let line_idx = match self.line_ends.binary_search(&offset) {
+        let line_idx = match line_ends.binary_search(&offset) {
             Ok(idx) => {
                 // Exact match with a line ending
                 // This offset is right after a line ending, so it's the start of the next line
@@ -76,84 +198,274 @@ impl LineTracker {
         let line_start_byte = if line_idx == 0 {
             0
         } else {
-            self.line_ends[line_idx - 1]
+            line_ends[line_idx - 1]
         };
 
-        // Ensure we don't try to slice in the middle of a UTF-8 character
-        // Find the nearest valid char boundary
-        let safe_offset = if offset < self.text.len() && !self.text.is_char_boundary(offset) {
-            // Walk backward to find a valid boundary
-            (0..offset)
-                .rev()
-                .find(|&i| self.text.is_char_boundary(i))
-                .unwrap_or(offset)
-        } else {
-            offset
-        };
-
-        // Count characters (not bytes) from line start to offset
-        // This ensures proper column numbers for multi-byte UTF-8 characters
-        let column =
-            if line_start_byte <= safe_offset && self.text.is_char_boundary(line_start_byte) {
-                self.text[line_start_byte..safe_offset].chars().count()
-            } else {
-                0
-            };
-
-        // Return 1-indexed values
-        (line_idx + 1, column + 1)
+        (line_idx, line_start_byte, offset)
     }
 
     /// Get the total number of lines
     pub fn line_count(&self) -> usize {
-        self.line_ends.len()
+        self.index.line_ends.len()
+    }
+
+    /// Byte range of a line (1-indexed), including its line ending
+    /// Returns `None` for lines past the end of the text
+    pub fn line_span(&self, line: usize) -> Option<Range<usize>> {
+        let line_ends = &self.index.line_ends;
+        let end = *line_ends.get(line.checked_sub(1)?)?;
+        let start = if line == 1 { 0 } else { line_ends[line - 2] };
+        Some(start..end)
+    }
+
+    /// Convert a byte offset to an offset in Unicode scalar values (chars)
+    pub fn char_offset(&self, offset: usize) -> usize {
+        self.index.offsets.char_offset(self.text, offset)
+    }
+
+    /// Convert a byte offset to an offset in UTF-16 code units, as used by JavaScript strings
+    pub fn utf16_offset(&self, offset: usize) -> usize {
+        self.index.offsets.utf16_offset(self.text, offset)
     }
 }
 
+/// Bytes between checkpoints in an `OffsetIndex`
+const CHECKPOINT_BYTES: usize = 256;
+
+/// Char and UTF-16 offsets at one byte offset
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Checkpoint {
+    byte: usize,
+    char: usize,
+    utf16: usize,
+}
+
+/// Conversion table from byte offsets to char and UTF-16 offsets for one text
+/// Holds a checkpoint about every `CHECKPOINT_BYTES` bytes, so a lookup is a binary
+/// search plus a scan of at most one interval; ASCII text needs no checkpoints at all
+#[derive(Debug, Clone, Default)]
+pub struct OffsetIndex {
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl OffsetIndex {
+    /// Build the table for `text`; lookups must pass the same text
+    pub fn new(text: &str) -> Self {
+        if text.is_ascii() {
+            return Self::default();
+        }
+
+        let mut checkpoints = vec![Checkpoint::default()];
+        let mut current = Checkpoint::default();
+        for ch in text.chars() {
+            current.byte += ch.len_utf8();
+            current.char += 1;
+            current.utf16 += ch.len_utf16();
+            if current.byte - checkpoints.last().unwrap().byte >= CHECKPOINT_BYTES {
+                checkpoints.push(current);
+            }
+        }
+
+        Self { checkpoints }
+    }
+
+    /// Char offset of a byte offset (rounded down to a char boundary)
+    pub fn char_offset(&self, text: &str, offset: usize) -> usize {
+        self.lookup(text, offset).char
+    }
+
+    /// UTF-16 offset of a byte offset (rounded down to a char boundary)
+    pub fn utf16_offset(&self, text: &str, offset: usize) -> usize {
+        self.lookup(text, offset).utf16
+    }
+
+    fn lookup(&self, text: &str, offset: usize) -> Checkpoint {
+        let mut offset = offset.min(text.len());
+        if self.checkpoints.is_empty() {
+            // ASCII: every unit is one byte
+            return Checkpoint {
+                byte: offset,
+                char: offset,
+                utf16: offset,
+            };
+        }
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let idx = self.checkpoints.partition_point(|c| c.byte <= offset) - 1;
+        let mut position = self.checkpoints[idx];
+        for ch in text[position.byte..offset].chars() {
+            position.char += 1;
+            position.utf16 += ch.len_utf16();
+        }
+        position.byte = offset;
+        position
+    }
+}
+
+/// Words that start with an apostrophe marking elided letters ('tis, 'em, 'cause)
+const ELIDED_WORDS: &[&str] = &[
+    "bout", "cause", "em", "n", "round", "til", "tis", "twas", "twere", "twill",
+];
+
+/// A quotation opened but not yet closed
+struct OpenQuote {
+    /// The opening mark
+    mark: char,
+    /// Byte offset of the opening mark
+    start: usize,
+    /// Marks that close this quotation
+    closers: &'static [char],
+}
+
 /// Quote span tracker for filtering matches inside quotes
 pub struct QuoteTracker {
-    /// List of (start, end) byte offsets for quoted spans
+    /// List of (start, end) byte offsets for quoted spans, sorted and disjoint
     spans: Vec<(usize, usize)>,
+    /// Curly single-quoted (\u{2018}...\u{2019}) quotations not inside another quotation
+    #[cfg_attr(not(feature = "typography"), allow(dead_code))]
+    outer_single: Vec<(usize, usize)>,
 }
 
 impl QuoteTracker {
     /// Create a new QuoteTracker from text
+    ///
+    /// Quotation marks are paired with a stack, so quotes nest. Straight and
+    /// right single quotes inside words ("don't"), after them ("dogs'") and
+    /// before elided years or words ('90s, 'tis) are apostrophes, not quotes.
+    /// A mark after a letter and before a lowercase word ("the boys' toys",
+    /// "rock 'n' roll") only closes a quotation that can't close later on.
+    /// Besides straight and curly quotes, low-9 („…“, ‚…‘) and guillemets (« », » «)
+    /// are recognized. A quotation still open at a paragraph break continues if
+    /// the next paragraph reopens it, as in multi-paragraph quotations, and is
+    /// otherwise dropped.
     pub fn new(text: &str) -> Self {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
         let mut spans = Vec::new();
-        let chars: Vec<char> = text.chars().collect();
-        let bytes: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
-
-        // Track different quote types
-        let quote_pairs = [
-            ('"', '"'),               // Straight double quotes
-            ('\'', '\''),             // Straight single quotes
-            ('\u{201C}', '\u{201D}'), // Curly double quotes
-            ('\u{2018}', '\u{2019}'), // Curly single quotes
-        ];
+        let mut outer_single = Vec::new();
+        let mut open: Vec<OpenQuote> = Vec::new();
+
+        let mut i = 0;
+        while i < chars.len() {
+            let (byte, ch) = chars[i];
+            let prev = i.checked_sub(1).map(|j| chars[j].1);
+            let next = chars.get(i + 1).map(|&(_, c)| c);
+
+            if ch == '\n' || ch == '\r' {
+                if let Some(reopen) = paragraph_start(&chars, i) {
+                    // Keep the outermost quotation if the paragraph reopens it
+                    let continued = open.first().is_some_and(|quote| {
+                        chars.get(reopen).is_some_and(|&(_, c)| c == quote.mark)
+                    });
+                    if continued {
+                        open.truncate(1);
+                        i = reopen + 1;
+                    } else {
+                        open.clear();
+                        i = reopen;
+                    }
+                    continue;
+                }
+                i += 1;
+                continue;
+            }
 
-        for (open, close) in quote_pairs {
-            let mut i = 0;
-            while i < chars.len() {
-                if chars[i] == open {
-                    let start = bytes[i];
-                    i += 1;
-                    // Find matching close quote
-                    while i < chars.len() && chars[i] != close {
-                        i += 1;
+            let opening = opens_after(prev) && next.is_some_and(|c| !c.is_whitespace());
+            let closes =
+                |open: &[OpenQuote]| open.iter().rposition(|quote| quote.closers.contains(&ch));
+
+            let mut close_at = None;
+            let mut opens: Option<&'static [char]> = None;
+            match ch {
+                '\'' | '\u{2018}' | '\u{2019}' => {
+                    let in_word = prev.is_some_and(char::is_alphanumeric)
+                        && next.is_some_and(char::is_alphanumeric);
+                    if in_word {
+                        // Contraction ("don't") - an apostrophe
+                    } else if opening {
+                        if ch != '\u{2019}' && !is_elision(&text[byte + ch.len_utf8()..]) {
+                            opens = Some(if ch == '\'' { &['\''] } else { &['\u{2019}'] });
+                        }
+                    } else {
+                        // Closes a quotation, or else is a possessive ("dogs'")
+                        close_at = closes(&open).filter(|&idx| {
+                            !(prev.is_some_and(char::is_alphabetic)
+                                && before_lowercase_word(&chars, i)
+                                && closes_later(text, &chars, i, open[idx].closers))
+                        });
                     }
-                    if i < chars.len() {
-                        let end = bytes[i] + chars[i].len_utf8();
-                        spans.push((start, end));
+                }
+                '"' => {
+                    if opening {
+                        opens = Some(&['"']);
+                    } else {
+                        close_at = closes(&open);
                     }
                 }
-                i += 1;
+                '\u{201C}' => {
+                    // Opens in English, closes a German low-9 quotation („…“)
+                    close_at = closes(&open).filter(|_| !opening);
+                    if close_at.is_none() {
+                        opens = Some(&['\u{201D}']);
+                    }
+                }
+                '\u{201D}' => close_at = closes(&open),
+                '\u{201E}' => opens = Some(&['\u{201C}', '\u{201D}']),
+                '\u{201A}' if opening => opens = Some(&['\u{2018}', '\u{2019}']),
+                // «French» (often with inner spaces) and »German« guillemets
+                '\u{00AB}' => {
+                    close_at = closes(&open).filter(|_| !opening);
+                    if close_at.is_none() {
+                        opens = Some(&['\u{00BB}']);
+                    }
+                }
+                '\u{00BB}' => {
+                    close_at = closes(&open);
+                    if close_at.is_none() && opening {
+                        opens = Some(&['\u{00AB}']);
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(idx) = close_at {
+                spans.push((open[idx].start, byte + ch.len_utf8()));
+                if idx == 0 && open[0].mark == '\u{2018}' {
+                    outer_single.push((open[0].start, byte + ch.len_utf8()));
+                }
+                open.truncate(idx);
+            } else if let Some(closers) = opens {
+                open.push(OpenQuote {
+                    mark: ch,
+                    start: byte,
+                    closers,
+                });
             }
+            i += 1;
         }
 
-        // Sort spans by start position
+        // Nested quotations lie inside their outer span; keep disjoint spans only
         spans.sort_by_key(|s| s.0);
+        let mut merged: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
+        for (start, end) in spans {
+            match merged.last_mut() {
+                Some(last) if start < last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
 
-        Self { spans }
+        Self {
+            spans: merged,
+            outer_single,
+        }
+    }
+
+    /// Curly single-quoted quotations that aren't nested in another quotation,
+    /// in order of their closing mark
+    #[cfg_attr(not(feature = "typography"), allow(dead_code))]
+    pub(crate) fn outer_single_quotes(&self) -> &[(usize, usize)] {
+        &self.outer_single
     }
 
     /// Check if a byte offset is inside a quoted span
@@ -218,6 +530,226 @@ impl QuoteTracker {
     }
 }
 
+/// Whether a quotation mark after `prev` is in opening position: at the start of
+/// the text, after whitespace or opening punctuation, or after another quote
+fn opens_after(prev: Option<char>) -> bool {
+    prev.is_none_or(|c| {
+        c.is_whitespace()
+            || matches!(
+                c,
+                '(' | '['
+                    | '{'
+                    | '\u{2014}'
+                    | '\u{2013}'
+                    | '-'
+                    | '/'
+                    | '"'
+                    | '\''
+                    | '\u{2018}'
+                    | '\u{201C}'
+                    | '\u{201A}'
+                    | '\u{201E}'
+                    | '\u{00AB}'
+                    | '\u{00BB}'
+            )
+    })
+}
+
+/// Whether text after an apostrophe is an elided year or word ('90s, 'tis)
+fn is_elision(rest: &str) -> bool {
+    let word: String = rest
+        .chars()
+        .take_while(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    word.starts_with(|c: char| c.is_ascii_digit()) || ELIDED_WORDS.contains(&word.as_str())
+}
+
+/// Whether `chars[idx]` is followed by a space and a lowercase word
+fn before_lowercase_word(chars: &[(usize, char)], idx: usize) -> bool {
+    chars.get(idx + 1).is_some_and(|&(_, c)| c == ' ')
+        && chars.get(idx + 2).is_some_and(|&(_, c)| c.is_lowercase())
+}
+
+/// Whether one of `closers` after `chars[idx]` could close a quotation before the
+/// paragraph ends or a new quotation opens with the same mark
+fn closes_later(text: &str, chars: &[(usize, char)], idx: usize, closers: &[char]) -> bool {
+    for j in idx + 1..chars.len() {
+        let (byte, ch) = chars[j];
+        if ch == '\n' && paragraph_start(chars, j).is_some() {
+            return false;
+        }
+        if !closers.contains(&ch) {
+            continue;
+        }
+        let prev = chars[j - 1].1;
+        let next = chars.get(j + 1).map(|&(_, c)| c);
+        if prev.is_alphanumeric() && next.is_some_and(char::is_alphanumeric) {
+            // Contraction
+            continue;
+        }
+        let opening = opens_after(Some(prev)) && next.is_some_and(|c| !c.is_whitespace());
+        if !opening {
+            return true;
+        }
+        if ch != '\u{2019}' && !is_elision(&text[byte + ch.len_utf8()..]) {
+            return false;
+        }
+    }
+    false
+}
+
+/// If the line break at `chars[idx]` ends a paragraph (a blank line follows),
+/// the index of the next paragraph's first non-whitespace character (or the end)
+fn paragraph_start(chars: &[(usize, char)], idx: usize) -> Option<usize> {
+    let mut breaks = 0;
+    let mut j = idx;
+    while let Some(&(_, c)) = chars.get(j) {
+        if c == '\n' || (c == '\r' && chars.get(j + 1).is_none_or(|&(_, n)| n != '\n')) {
+            breaks += 1;
+        } else if !c.is_whitespace() {
+            break;
+        }
+        j += 1;
+    }
+    (breaks >= 2).then_some(j)
+}
+
+/// Kind of text that prose checks leave alone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtectedKind {
+    /// `https://example.com/path`, `www.example.com`
+    Url,
+    /// `name@example.com`
+    Email,
+    /// `/usr/bin`, `~/notes`, `src/main.rs`, `C:\Users`
+    Path,
+    /// `camelCase` and `snake_case` names
+    Identifier,
+    /// `1.2.3`, `v2.0`, `1.0.0-beta.1`
+    Version,
+    /// ISO 8601 dates and timestamps (`2026-10-17`, `2026-10-17T09:30Z`)
+    Date,
+    /// `555-123-4567`, `(555) 123-4567`, `+44 20 7946 0958`
+    Phone,
+    /// Hyphenated ISBNs (`978-0-306-40615-7`)
+    Isbn,
+    /// `0x1F`
+    Hex,
+}
+
+/// One alternative per kind, tried in this order where several match at the same position
+const PROTECTED_PATTERNS: &[(ProtectedKind, &str)] = &[
+    (
+        ProtectedKind::Url,
+        r#"(?:[a-zA-Z][a-zA-Z0-9+.-]*://|www\.)[^\s<>"'`]*[^\s<>"'`.,;:!?)\]]"#,
+    ),
+    (ProtectedKind::Email, r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+"),
+    (
+        ProtectedKind::Date,
+        r"\b\d{4}-\d{2}-\d{2}(?:T\d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?(?:Z|[+-]\d{2}:?\d{2})?)?\b",
+    ),
+    (
+        ProtectedKind::Version,
+        r"\bv?\d+\.\d+\.\d+(?:[-+][\w.]*\w)?\b|\bv\d+\.\d+\b",
+    ),
+    (
+        ProtectedKind::Isbn,
+        r"\b(?:97[89]-)?\d{1,5}-\d{1,7}-\d{1,7}-[\dX]\b",
+    ),
+    (
+        ProtectedKind::Phone,
+        r"\+\d{1,3}(?:[ .-]?\(?\d{1,4}\)?){2,4}\b|\(\d{2,4}\) ?\d{3,4}[.-]\d{3,4}\b|\b\d{2,4}[.-]\d{3,4}[.-]\d{3,4}\b",
+    ),
+    (ProtectedKind::Hex, r"\b0[xX][0-9a-fA-F]+\b"),
+    (
+        ProtectedKind::Path,
+        r#"\B(?:~|\.{1,2})?(?:/[\w.-]*\w)+/?|\b[A-Za-z]:\\[^\s"'<>]*[^\s"'<>.,;:!?]|\b[\w.-]+(?:/[\w.-]+)*/[\w-]+\.[A-Za-z][A-Za-z0-9]{0,5}\b"#,
+    ),
+    (
+        ProtectedKind::Identifier,
+        r"\b[a-z][a-z0-9]*(?:[A-Z][a-z0-9]*)+\b|\b_*[A-Za-z][A-Za-z0-9]*(?:_+[A-Za-z0-9]+)+_*\b",
+    ),
+];
+
+/// All protected patterns as one regex, one capture group per kind
+fn protected_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        let alternatives: Vec<String> = PROTECTED_PATTERNS
+            .iter()
+            .map(|(_, pattern)| format!("({})", pattern))
+            .collect();
+        Regex::new(&alternatives.join("|")).expect("invalid protected span pattern")
+    })
+}
+
+/// A protected span of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtectedSpan {
+    /// Start position (0-indexed byte offset)
+    pub start: usize,
+    /// End position (0-indexed byte offset)
+    pub end: usize,
+    /// What the span contains
+    pub kind: ProtectedKind,
+}
+
+/// Protected span tracker for skipping matches inside URLs, paths, identifiers
+/// and numbers that aren't prose
+pub struct ProtectedSpanTracker {
+    /// Sorted, disjoint protected spans
+    spans: Vec<ProtectedSpan>,
+}
+
+impl ProtectedSpanTracker {
+    /// Create a new ProtectedSpanTracker from text
+    /// A single scan takes the leftmost match, preferring kinds listed earlier,
+    /// so a URL containing a path or an identifier is one URL span
+    pub fn new(text: &str) -> Self {
+        let spans = protected_regex()
+            .captures_iter(text)
+            .filter_map(|caps| {
+                let (idx, group) = caps
+                    .iter()
+                    .skip(1)
+                    .enumerate()
+                    .find_map(|(idx, group)| group.map(|group| (idx, group)))?;
+                Some(ProtectedSpan {
+                    start: group.start(),
+                    end: group.end(),
+                    kind: PROTECTED_PATTERNS[idx].0,
+                })
+            })
+            .collect();
+
+        Self { spans }
+    }
+
+    /// The protected spans, in order
+    pub fn spans(&self) -> &[ProtectedSpan] {
+        &self.spans
+    }
+
+    /// The kind of protected span containing a byte offset, if any
+    pub fn kind_at(&self, offset: usize) -> Option<ProtectedKind> {
+        let idx = self.spans.partition_point(|span| span.start <= offset);
+        idx.checked_sub(1)
+            .map(|idx| self.spans[idx])
+            .filter(|span| offset < span.end)
+            .map(|span| span.kind)
+    }
+
+    /// Check if a range lies entirely inside one protected span
+    /// Matches that only touch a span (a sentence containing a URL) are not protected
+    pub fn contains(&self, start: usize, end: usize) -> bool {
+        let idx = self.spans.partition_point(|span| span.start <= start);
+        idx.checked_sub(1)
+            .is_some_and(|idx| end <= self.spans[idx].end && start < self.spans[idx].end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +862,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_offset_index() {
+        let text = "Hi 👋 日本, café".repeat(100);
+        let index = OffsetIndex::new(&text);
+        for (byte, _) in text.char_indices().chain([(text.len(), ' ')]) {
+            assert_eq!(index.char_offset(&text, byte), text[..byte].chars().count());
+            assert_eq!(
+                index.utf16_offset(&text, byte),
+                text[..byte].encode_utf16().count()
+            );
+        }
+        // Inside a character rounds down to its start
+        assert_eq!(index.utf16_offset(&text, 4), 3);
+
+        let ascii = "plain text";
+        let index = OffsetIndex::new(ascii);
+        assert_eq!(index.utf16_offset(ascii, 5), 5);
+        assert_eq!(index.char_offset(ascii, 50), ascii.len());
+    }
+
+    #[test]
+    fn test_line_tracker_long_lines() {
+        // Long lines with multi-byte characters, as in minified HTML
+        let text = format!(
+            "{}\n{}",
+            "naïve café 👋 ".repeat(500),
+            "日本語 text ".repeat(500)
+        );
+        let tracker = LineTracker::new(&text);
+        let second_line = text.find('\n').unwrap() + 1;
+
+        for (byte, _) in text.char_indices().step_by(7) {
+            let (line, line_start) = if byte < second_line {
+                (1, 0)
+            } else {
+                (2, second_line)
+            };
+            let column = text[line_start..byte].chars().count() + 1;
+            assert_eq!(tracker.offset_to_position(byte), (line, column));
+        }
+
+        // A tracker over a shared index gives the same positions
+        let index = LineIndex::new(&text);
+        let shared = LineTracker::with_index(&text, &index);
+        assert_eq!(shared.line_count(), 2);
+        assert_eq!(
+            shared.offset_to_position(text.len()),
+            tracker.offset_to_position(text.len())
+        );
+    }
+
     #[test]
     fn test_line_tracker() {
         let text = "Hello\nWorld\nTest";
@@ -346,6 +929,44 @@ mod tests {
         assert_eq!(tracker.offset_to_position(12), (3, 1)); // T
     }
 
+    #[test]
+    fn test_lsp_range() {
+        let text = "Hi 👋 there\r\nnext line\nend";
+        let tracker = LineTracker::new(text);
+        let there = text.find("there").unwrap();
+        let next = text.find("next").unwrap();
+
+        // "there" sits after a 4-byte, 2-unit, 1-char emoji
+        let at = |line, character| LspPosition { line, character };
+        assert_eq!(
+            tracker.lsp_position(there, PositionEncoding::Utf8),
+            at(0, 8)
+        );
+        assert_eq!(
+            tracker.lsp_position(there, PositionEncoding::Utf16),
+            at(0, 6)
+        );
+        assert_eq!(
+            tracker.lsp_position(there, PositionEncoding::Utf32),
+            at(0, 5)
+        );
+
+        // A range across lines ends on the later line
+        let range = tracker.lsp_range(there..next + 4, PositionEncoding::Utf16);
+        assert_eq!(range.start, at(0, 6));
+        assert_eq!(range.end, at(1, 4));
+
+        // Ending on a line break ends at the start of the next line
+        let range = tracker.lsp_range(next..next + 10, PositionEncoding::Utf16);
+        assert_eq!(range.end, at(2, 0));
+
+        let empty = LineTracker::new("");
+        assert_eq!(
+            empty.lsp_range(0..0, PositionEncoding::Utf8).end,
+            LspPosition::default()
+        );
+    }
+
     #[test]
     fn test_line_tracker_utf8_emoji() {
         // Test with emoji (4-byte UTF-8 characters)
@@ -433,6 +1054,12 @@ mod tests {
         assert_eq!(tracker.offset_to_position(13), (3, 1)); // L in Line3 (after \r\n)
         assert_eq!(tracker.offset_to_position(19), (4, 1)); // L in Line4 (after \r)
         assert_eq!(tracker.line_count(), 4);
+
+        assert_eq!(tracker.line_span(1), Some(0..6));
+        assert_eq!(tracker.line_span(2), Some(6..13));
+        assert_eq!(tracker.line_span(4), Some(19..24));
+        assert_eq!(tracker.line_span(0), None);
+        assert_eq!(tracker.line_span(5), None);
     }
 
     #[test]
@@ -511,4 +1138,195 @@ mod tests {
         assert!(tracker.is_in_quote(39)); // n in not
         assert!(tracker.is_in_quote(43)); // g in great
     }
+
+    #[test]
+    fn test_quote_tracker_apostrophes() {
+        // Contractions don't pair up into a quotation
+        let text = "don't do it, it's very fine";
+        let tracker = QuoteTracker::new(text);
+        assert!(!tracker.is_in_quote(text.find("very").unwrap()));
+
+        // Possessives and elided years or words
+        for text in [
+            "the dogs' bowls were very full, and the cats' too",
+            "in the '90s it was very popular, by the '00s it wasn't",
+            "'Tis very cold, and rock 'n' roll is loud",
+            "James’ car was very fast in the ’80s",
+        ] {
+            let tracker = QuoteTracker::new(text);
+            assert!(!tracker.is_in_quote(text.find("very").unwrap()), "{}", text);
+        }
+
+        // A quotation that contains an apostrophe
+        let text = "She said 'don't be very late' and left";
+        let tracker = QuoteTracker::new(text);
+        assert!(tracker.is_in_quote(text.find("very").unwrap()));
+        assert!(!tracker.is_in_quote(text.find("left").unwrap()));
+    }
+
+    #[test]
+    fn test_quote_tracker_possessive_in_quote() {
+        // A plural possessive or elision inside a single-quoted quotation
+        for text in [
+            "'Hello,' he said, 'the boys' toys are very old.' She left.",
+            "'I like rock 'n' roll, it is very loud,' she left.",
+            "\u{2018}The boys\u{2019} toys are very old.\u{2019} She left.",
+        ] {
+            let tracker = QuoteTracker::new(text);
+            assert!(tracker.is_in_quote(text.find("very").unwrap()), "{}", text);
+            assert!(!tracker.is_in_quote(text.find("left").unwrap()), "{}", text);
+        }
+
+        // Without a later closer the mark still closes the quotation
+        let text = "She said 'stop' and very soon left.";
+        let tracker = QuoteTracker::new(text);
+        assert!(tracker.is_in_quote(text.find("stop").unwrap()));
+        assert!(!tracker.is_in_quote(text.find("very").unwrap()));
+
+        // Nor is a later quotation of its own taken as the closer
+        let text = "She called it 'fine' and very soon 'good'.";
+        let tracker = QuoteTracker::new(text);
+        assert!(tracker.is_in_quote(text.find("fine").unwrap()));
+        assert!(!tracker.is_in_quote(text.find("very").unwrap()));
+        assert!(tracker.is_in_quote(text.find("good").unwrap()));
+    }
+
+    #[test]
+    fn test_quote_tracker_nested() {
+        let text = "He said, \"She told me 'stop', very loudly.\" Then left.";
+        let tracker = QuoteTracker::new(text);
+        assert!(tracker.is_in_quote(text.find("stop").unwrap()));
+        assert!(tracker.is_in_quote(text.find("very").unwrap()));
+        assert!(!tracker.is_in_quote(text.find("Then").unwrap()));
+
+        let text = "\u{201C}Outer \u{2018}inner\u{2019} very\u{201D} after";
+        let tracker = QuoteTracker::new(text);
+        assert!(tracker.is_in_quote(text.find("very").unwrap()));
+        assert!(!tracker.is_in_quote(text.find("after").unwrap()));
+    }
+
+    #[test]
+    fn test_quote_tracker_paragraphs() {
+        // Each paragraph of a long quotation reopens it; only the last closes it
+        let text = "\"First part, very long.\n\n\"Second part.\" Narration is very plain.";
+        let tracker = QuoteTracker::new(text);
+        assert!(tracker.is_in_quote(text.find("very").unwrap()));
+        assert!(tracker.is_in_quote(text.find("Second").unwrap()));
+        assert!(!tracker.is_in_quote(text.find("Narration").unwrap()));
+
+        // An unclosed quotation ends with its paragraph
+        let text = "An \"unclosed quote.\n\nA very \"real\" quote.";
+        let tracker = QuoteTracker::new(text);
+        assert!(!tracker.is_in_quote(text.find("very").unwrap()));
+        assert!(tracker.is_in_quote(text.find("real").unwrap()));
+    }
+
+    #[test]
+    fn test_quote_tracker_low9_and_guillemets() {
+        for text in [
+            "Er sagte \u{201E}sehr gut\u{201C} und ging",
+            "Er sagte \u{201A}sehr gut\u{2018} und ging",
+            "Il a dit \u{00AB} sehr gut \u{00BB} und ging",
+            "Er sagte \u{00BB}sehr gut\u{00AB} und ging",
+        ] {
+            let tracker = QuoteTracker::new(text);
+            assert!(tracker.is_in_quote(text.find("sehr").unwrap()), "{}", text);
+            assert!(!tracker.is_in_quote(text.find("und").unwrap()), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_protected_span_kinds() {
+        let cases = [
+            (
+                "See https://example.com/very_good?q=1.",
+                "https://example.com/very_good?q=1",
+                ProtectedKind::Url,
+            ),
+            (
+                "Mail jane.doe+x@example.co.uk today",
+                "jane.doe+x@example.co.uk",
+                ProtectedKind::Email,
+            ),
+            (
+                "Logs go to ~/.local/state/app.log.",
+                "~/.local/state/app.log",
+                ProtectedKind::Path,
+            ),
+            ("Edit src/main.rs first", "src/main.rs", ProtectedKind::Path),
+            (
+                "Open C:\\Users\\me\\notes.txt now",
+                "C:\\Users\\me\\notes.txt",
+                ProtectedKind::Path,
+            ),
+            (
+                "Call getValue or max_retries",
+                "getValue",
+                ProtectedKind::Identifier,
+            ),
+            (
+                "Upgrade to 1.10.2-beta.1 soon",
+                "1.10.2-beta.1",
+                ProtectedKind::Version,
+            ),
+            (
+                "Released on 2026-10-17T09:30Z",
+                "2026-10-17T09:30Z",
+                ProtectedKind::Date,
+            ),
+            (
+                "Call (555) 123-4567 now",
+                "(555) 123-4567",
+                ProtectedKind::Phone,
+            ),
+            (
+                "Call +44 20 7946 0958 now",
+                "+44 20 7946 0958",
+                ProtectedKind::Phone,
+            ),
+            (
+                "ISBN 978-0-306-40615-7",
+                "978-0-306-40615-7",
+                ProtectedKind::Isbn,
+            ),
+            ("Set it to 0x1F", "0x1F", ProtectedKind::Hex),
+        ];
+        for (text, span, kind) in cases {
+            let tracker = ProtectedSpanTracker::new(text);
+            let start = text.find(span).unwrap();
+            assert_eq!(
+                tracker.spans().first(),
+                Some(&ProtectedSpan {
+                    start,
+                    end: start + span.len(),
+                    kind
+                }),
+                "{}",
+                text
+            );
+        }
+
+        let tracker = ProtectedSpanTracker::new("Call getValue or max_retries");
+        assert_eq!(tracker.kind_at(19), Some(ProtectedKind::Identifier));
+    }
+
+    #[test]
+    fn test_protected_span_prose() {
+        // Ranges, fractions and slashed words are prose
+        for text in [
+            "Pages 10-20 cover 1990-1995.",
+            "Use 1/2 cup and/or salt, w/o sugar.",
+            "The price rose 3.5 percent.",
+            "A plain sentence with Capitalized Words.",
+        ] {
+            assert_eq!(ProtectedSpanTracker::new(text).spans(), &[], "{}", text);
+        }
+
+        let text = "Visit https://example.com/docs today";
+        let tracker = ProtectedSpanTracker::new(text);
+        let url = text.find("https").unwrap();
+        assert!(tracker.contains(url + 8, url + 15));
+        // A match that only touches the span (a whole sentence) isn't protected
+        assert!(!tracker.contains(0, text.len()));
+    }
 }
@@ -0,0 +1,329 @@
+//! Tokenizer module for proselint-wasm
+//!
+//! Splits text into words and sentences for checks that can't be expressed
+//! as a single regex.
+
+use std::ops::Range;
+
+/// A word with its byte span in the original text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Word<'a> {
+    /// The word as it appears in the text
+    pub text: &'a str,
+    /// Start position (0-indexed byte offset)
+    pub start: usize,
+    /// End position (0-indexed byte offset)
+    pub end: usize,
+}
+
+/// Iterator over the words of a text
+pub struct Words<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+/// Split text into words: runs of letters and digits, joined by
+/// internal apostrophes or hyphens ("don't", "well-known")
+pub fn words(text: &str) -> Words<'_> {
+    Words { text, pos: 0 }
+}
+
+fn is_joiner(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}' | '-')
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = Word<'a>;
+
+    fn next(&mut self) -> Option<Word<'a>> {
+        let rest = &self.text[self.pos..];
+        let offset = rest.find(char::is_alphanumeric)?;
+        let start = self.pos + offset;
+
+        let mut end = start;
+        let mut chars = self.text[start..].char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c.is_alphanumeric() {
+                end = start + i + c.len_utf8();
+            } else if is_joiner(c) && chars.peek().is_some_and(|&(_, n)| n.is_alphanumeric()) {
+                continue;
+            } else {
+                break;
+            }
+        }
+
+        self.pos = end;
+        Some(Word {
+            text: &self.text[start..end],
+            start,
+            end,
+        })
+    }
+}
+
+/// Split text into sentence spans (trimmed of surrounding whitespace)
+/// A sentence ends at '.', '!' or '?' (plus closing quotes or brackets) followed
+/// by whitespace and a character that isn't lowercase, or at a blank line
+pub fn sentences(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let boundary = match c {
+            '.' | '!' | '?' => {
+                let mut end = i + c.len_utf8();
+                while let Some(&(j, n)) = chars.peek() {
+                    if matches!(
+                        n,
+                        '.' | '!' | '?' | '"' | '\'' | ')' | ']' | '\u{201D}' | '\u{2019}'
+                    ) {
+                        end = j + n.len_utf8();
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let rest = text[end..].trim_start();
+                let next_is_space = text[end..].starts_with(char::is_whitespace);
+                let next_starts_sentence = !rest.starts_with(|n: char| n.is_lowercase());
+                (rest.is_empty() || next_is_space && next_starts_sentence).then_some(end)
+            }
+            '\n' if is_blank_line_after(&text[i + 1..]) => Some(i),
+            _ => None,
+        };
+
+        if let Some(end) = boundary {
+            push_trimmed(&mut spans, text, start..end);
+            start = end;
+        }
+    }
+
+    push_trimmed(&mut spans, text, start..text.len());
+    spans
+}
+
+/// Returns true if the text starts with an (otherwise empty) line ending in '\n'
+fn is_blank_line_after(rest: &str) -> bool {
+    rest.split('\n')
+        .next()
+        .is_some_and(|line| line.trim().is_empty() && rest.contains('\n'))
+}
+
+fn push_trimmed(spans: &mut Vec<Range<usize>>, text: &str, range: Range<usize>) {
+    let slice = &text[range.clone()];
+    let trimmed = slice.trim_start();
+    let start = range.start + (slice.len() - trimmed.len());
+    let end = start + trimmed.trim_end().len();
+    if start < end {
+        spans.push(start..end);
+    }
+}
+
+/// Split text into prose paragraphs, skipping Markdown structure
+/// Headings, list items and fenced code blocks are left out; blank lines,
+/// and any skipped line, end the current paragraph
+pub fn prose_paragraphs(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut current: Option<Range<usize>> = None;
+    let mut fence: Option<&str> = None;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim();
+
+        let fence_marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        let is_prose = match (fence, fence_marker) {
+            (Some(open), Some(marker)) if open == marker => {
+                fence = None;
+                false
+            }
+            (Some(_), _) => false,
+            (None, Some(marker)) => {
+                fence = Some(marker);
+                false
+            }
+            (None, None) => !trimmed.is_empty() && !is_heading(trimmed) && !is_list_item(trimmed),
+        };
+
+        if is_prose {
+            let end = start + line.trim_end().len();
+            current = Some(current.map_or(start..end, |span| span.start..end));
+        } else if let Some(span) = current.take() {
+            push_trimmed(&mut spans, text, span);
+        }
+    }
+
+    if let Some(span) = current {
+        push_trimmed(&mut spans, text, span);
+    }
+    spans
+}
+
+/// "# Title", "## Section"
+fn is_heading(line: &str) -> bool {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    (1..=6).contains(&hashes) && (line.len() == hashes || line[hashes..].starts_with([' ', '\t']))
+}
+
+/// "- item", "* item", "+ item", "1. item", "2) item"
+fn is_list_item(line: &str) -> bool {
+    if line.starts_with(['-', '*', '+']) {
+        return line[1..].starts_with([' ', '\t']);
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    digits > 0
+        && line[digits..].starts_with(['.', ')'])
+        && line[digits + 1..].starts_with([' ', '\t'])
+}
+
+/// Words ending in "ly" that aren't adverbs formed from another word
+const NOT_ADVERBS: &[&str] = &[
+    "anomaly",
+    "assembly",
+    "belly",
+    "bully",
+    "butterfly",
+    "comply",
+    "family",
+    "folly",
+    "holly",
+    "imply",
+    "italy",
+    "jelly",
+    "july",
+    "monopoly",
+    "multiply",
+    "rally",
+    "supply",
+];
+
+/// Shortest stem "ly" is stripped from ("quickly" -> "quick", but not "apply" -> "app")
+const MIN_LY_STEM: usize = 4;
+
+/// Reduce a word to a rough lemma for counting repetitions
+/// Lowercases and strips common inflections ("running" -> "run", "cities" -> "city")
+pub fn lemma(word: &str) -> String {
+    let mut lemma = word.to_lowercase().replace('\u{2019}', "'");
+    if let Some(stem) = lemma.strip_suffix("'s") {
+        lemma = stem.to_string();
+    }
+
+    let strip = |lemma: &str, suffix: &str, with: &str| -> Option<String> {
+        let stem = lemma.strip_suffix(suffix)?;
+        (stem.chars().count() >= 3).then(|| format!("{}{}", stem, with))
+    };
+
+    let stripped = strip(&lemma, "ies", "y")
+        .or_else(|| strip(&lemma, "ied", "y"))
+        .or_else(|| strip(&lemma, "sses", "ss"))
+        .or_else(|| {
+            ["ches", "shes", "xes", "zes"]
+                .iter()
+                .find_map(|suffix| strip(&lemma, suffix, &suffix[..suffix.len() - 2]))
+        })
+        .or_else(|| strip(&lemma, "ing", "").map(undouble))
+        .or_else(|| strip(&lemma, "ed", "").map(undouble))
+        .or_else(|| {
+            let stem = lemma.strip_suffix("ly")?;
+            (stem.chars().count() >= MIN_LY_STEM && !NOT_ADVERBS.contains(&lemma.as_str()))
+                .then(|| stem.to_string())
+        })
+        .or_else(|| {
+            let keeps_s = ["ss", "us", "is"].iter().any(|end| lemma.ends_with(end));
+            if keeps_s {
+                None
+            } else {
+                strip(&lemma, "s", "")
+            }
+        });
+
+    let lemma = stripped.unwrap_or(lemma);
+
+    // Drop a silent final 'e' so "make" and "making" share a lemma
+    match lemma.strip_suffix('e') {
+        Some(stem) if stem.chars().count() >= 3 => stem.to_string(),
+        _ => lemma,
+    }
+}
+
+/// "runn" -> "run", but keep "fall", "press" and "buzz"
+fn undouble(stem: String) -> String {
+    let mut chars = stem.chars().rev();
+    match (chars.next(), chars.next()) {
+        (Some(a), Some(b)) if a == b && !matches!(a, 'l' | 's' | 'z') && !"aeiou".contains(a) => {
+            stem[..stem.len() - a.len_utf8()].to_string()
+        }
+        _ => stem,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let tokens: Vec<&str> = words("Don't stop—the well-known café, 'quoted' 42.")
+            .map(|w| w.text)
+            .collect();
+        assert_eq!(
+            tokens,
+            ["Don't", "stop", "the", "well-known", "café", "quoted", "42"]
+        );
+    }
+
+    #[test]
+    fn test_sentences() {
+        let text = "First one. Second, e.g. this one!\n\nThird \"quoted.\" Fourth";
+        let spans: Vec<&str> = sentences(text).into_iter().map(|r| &text[r]).collect();
+        assert_eq!(
+            spans,
+            [
+                "First one.",
+                "Second, e.g. this one!",
+                "Third \"quoted.\"",
+                "Fourth"
+            ]
+        );
+
+        let paragraphs = "A heading\n\nNo final stop";
+        assert_eq!(sentences(paragraphs).len(), 2);
+    }
+
+    #[test]
+    fn test_prose_paragraphs() {
+        let text = "# Title\n\nFirst line\nsecond line.\n\n- item one\n- item two\n\n\
+                    ```\nlet x = 1;\n```\nAfter the fence.\n## Next";
+        let spans: Vec<&str> = prose_paragraphs(text)
+            .into_iter()
+            .map(|r| &text[r])
+            .collect();
+        assert_eq!(spans, ["First line\nsecond line.", "After the fence."]);
+    }
+
+    #[test]
+    fn test_lemma() {
+        assert_eq!(lemma("Running"), lemma("run"));
+        assert_eq!(lemma("cities"), "city");
+        assert_eq!(lemma("making"), lemma("make"));
+        assert_eq!(lemma("boxes"), "box");
+        assert_eq!(lemma("process"), "process");
+        assert_eq!(lemma("Smith's"), "smith");
+        assert_eq!(lemma("quickly"), "quick");
+        assert_eq!(lemma("apply"), "apply");
+        assert_eq!(lemma("reply"), "reply");
+        assert_eq!(lemma("family"), "family");
+        assert_eq!(lemma("supplies"), lemma("supply"));
+    }
+
+    #[test]
+    fn test_word_spans() {
+        let text = "naïve  idea";
+        let spans: Vec<_> = words(text).map(|w| (w.start, w.end)).collect();
+        assert_eq!(spans, [(0, 6), (8, 12)]);
+        assert_eq!(&text[8..12], "idea");
+    }
+}
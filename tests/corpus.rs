@@ -0,0 +1,176 @@
+//! Golden snapshot tests over a corpus of realistic prose
+//!
+//! Each file in `tests/corpus/` is linted with the default configuration and
+//! compared with its snapshot in `tests/corpus/snapshots/`. A mismatch fails
+//! with the hits added and removed, summarized per check ID.
+//!
+//! After an intended change, regenerate the snapshots and review the diff:
+//!
+//! ```bash
+//! UPDATE_SNAPSHOTS=1 cargo test --test corpus
+//! ```
+//!
+//! Snapshots cover the full registry, so these tests need every check category.
+
+#![cfg(feature = "all-checks")]
+
+use proselint_wasm::{LintResult, Linter};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CORPUS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/corpus");
+
+/// Render one result as a snapshot line: `line:column check "matched text"`
+fn snapshot_line(text: &str, result: &LintResult) -> String {
+    format!(
+        "{}:{} {} {:?}",
+        result.line,
+        result.column,
+        result.check,
+        &text[result.start..result.end]
+    )
+}
+
+fn render_snapshot(text: &str, results: &[LintResult]) -> String {
+    results
+        .iter()
+        .map(|r| snapshot_line(text, r) + "\n")
+        .collect()
+}
+
+/// The check ID of a snapshot line
+fn check_id(line: &str) -> &str {
+    line.split(' ').nth(1).unwrap_or("")
+}
+
+/// Hits added to and removed from a snapshot
+#[derive(Default)]
+struct SnapshotDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl SnapshotDiff {
+    fn new(expected: &str, actual: &str) -> Self {
+        let mut counts: HashMap<&str, isize> = HashMap::new();
+        for line in actual.lines() {
+            *counts.entry(line).or_default() += 1;
+        }
+        for line in expected.lines() {
+            *counts.entry(line).or_default() -= 1;
+        }
+
+        let mut diff = SnapshotDiff::default();
+        // Walk each side in document order so the listing reads top to bottom
+        for line in actual.lines() {
+            let count = counts.get_mut(line).unwrap();
+            if *count > 0 {
+                *count -= 1;
+                diff.added.push(line.to_string());
+            }
+        }
+        for line in expected.lines() {
+            let count = counts.get_mut(line).unwrap();
+            if *count < 0 {
+                *count += 1;
+                diff.removed.push(line.to_string());
+            }
+        }
+        diff
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Added and removed counts per check ID, sorted by ID
+    fn per_check(&self) -> BTreeMap<&str, (usize, usize)> {
+        let mut summary: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+        for line in &self.added {
+            summary.entry(check_id(line)).or_default().0 += 1;
+        }
+        for line in &self.removed {
+            summary.entry(check_id(line)).or_default().1 += 1;
+        }
+        summary
+    }
+
+    fn report(&self, name: &str) -> String {
+        let mut out = format!("{}: +{} -{}\n", name, self.added.len(), self.removed.len());
+        for (check, (added, removed)) in self.per_check() {
+            out.push_str(&format!("  +{:<3} -{:<3} {}\n", added, removed, check));
+        }
+        for line in &self.added {
+            out.push_str(&format!("  + {}\n", line));
+        }
+        for line in &self.removed {
+            out.push_str(&format!("  - {}\n", line));
+        }
+        out
+    }
+}
+
+fn corpus_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(CORPUS_DIR)
+        .expect("corpus directory is missing")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn snapshot_path(file: &Path) -> PathBuf {
+    Path::new(CORPUS_DIR)
+        .join("snapshots")
+        .join(file.file_stem().unwrap())
+        .with_extension("snap")
+}
+
+#[test]
+fn corpus_snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let linter = Linter::new();
+    let mut reports = Vec::new();
+
+    let files = corpus_files();
+    assert!(!files.is_empty(), "no corpus files in {}", CORPUS_DIR);
+
+    for file in files {
+        let text = fs::read_to_string(&file).unwrap();
+        let actual = render_snapshot(&text, &linter.check(&text));
+        let path = snapshot_path(&file);
+
+        if update {
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&path).unwrap_or_default();
+        let diff = SnapshotDiff::new(&expected, &actual);
+        if !diff.is_empty() {
+            let name = file.file_name().unwrap().to_string_lossy();
+            reports.push(diff.report(&name));
+        }
+    }
+
+    assert!(
+        reports.is_empty(),
+        "corpus results changed; review and rerun with UPDATE_SNAPSHOTS=1 to accept\n\n{}",
+        reports.join("\n")
+    );
+}
+
+#[test]
+fn snapshot_diff_per_check() {
+    let expected = "1:1 a.one \"x\"\n2:1 b.two \"y\"\n2:1 b.two \"y\"\n";
+    let actual = "1:1 a.one \"x\"\n2:1 b.two \"y\"\n3:1 c.three \"z\"\n";
+    let diff = SnapshotDiff::new(expected, actual);
+
+    assert_eq!(diff.added, vec!["3:1 c.three \"z\""]);
+    assert_eq!(diff.removed, vec!["2:1 b.two \"y\""]);
+    let summary = diff.per_check();
+    assert_eq!(summary["b.two"], (0, 1));
+    assert_eq!(summary["c.three"], (1, 0));
+}
@@ -155,21 +155,237 @@ async function runTests() {
     assert(typeof warmCount === 'number', 'warm_all() returns number');
     assert(warmCount > 0, 'warm_all() pre-compiles checks');
 
+    console.log('\n📦 Typed API Tests\n');
+
+    // Test 18: check returns objects
+    const typed = proselint.check('This is very very important.');
+    assert(Array.isArray(typed), 'check() returns array');
+    assertEquals(typed.length, parsed.length, 'check() matches lint()');
+    assertEquals(typeof typed[0].line, 'number', 'check() result has numeric line');
+    assertEquals(typed[0].replacement, parsed[0].replacement, 'check() keeps null replacement');
+
+    // Test 19: check throws InputError
+    try {
+      proselint.check(largeText);
+      assert(false, 'check() throws for oversized text');
+    } catch (e) {
+      assert(e instanceof Error, 'check() throws an Error');
+      assertEquals(e.name, 'InputError', 'Oversized text throws InputError');
+      assert(e.message.includes('too large'), 'InputError message mentions size limit');
+    }
+
+    // Test 20: check_batch
+    const typedBatch = proselint.check_batch(['This is very bad.', 'Really quite good.']);
+    assertEquals(typedBatch.length, 2, 'check_batch() processes all texts');
+    try {
+      proselint.check_batch(new Array(101).fill('test'));
+      assert(false, 'check_batch() throws for oversized batch');
+    } catch (e) {
+      assertEquals(e.name, 'InputError', 'Oversized batch throws InputError');
+    }
+
+    // Test 21: from_config
+    const typedLinter = wasm.Proselint.from_config({ checks: { weasel_words: false } });
+    const typedConfigResults = typedLinter.check('This is very good text.');
+    assert(!typedConfigResults.some(r => r.check.includes('weasel')), 'from_config() disables weasel word checks');
+    try {
+      wasm.Proselint.from_config({ max_errors: 'ten' });
+      assert(false, 'from_config() throws for invalid config');
+    } catch (e) {
+      assertEquals(e.name, 'ConfigError', 'Invalid config throws ConfigError');
+    }
+    assert(Array.isArray(typedLinter.config_warnings()), 'config_warnings() returns array');
+
+    // Test 22: check metadata
+    const ids = wasm.Proselint.check_ids();
+    assertEquals(ids.length, checksParsed.length, 'check_ids() matches available_checks()');
+    const info = wasm.Proselint.check_info(ids[0]);
+    assertEquals(info.id, ids[0], 'check_info() describes the check');
+    try {
+      wasm.Proselint.check_info('no.such.check');
+      assert(false, 'check_info() throws for unknown check');
+    } catch (e) {
+      assertEquals(e.name, 'UnknownCheckError', 'Unknown check throws UnknownCheckError');
+    }
+    assertEquals(wasm.Proselint.check_catalog().length, ids.length, 'check_catalog() covers every check');
+
+    console.log('\n📦 Lint Options Tests\n');
+
+    // Test 23: per-call options
+    const optionsText = 'This is very unique... It was very bad.';
+    const typographyOnly = JSON.parse(proselint.lint_with_options(optionsText, JSON.stringify({ categories: ['typography'] })));
+    assert(typographyOnly.length > 0, 'lint_with_options() finds typography issues');
+    assert(typographyOnly.every(r => r.check.startsWith('typography')), 'lint_with_options() filters by category');
+    const errorsOnly = proselint.check_with_options(optionsText, { min_severity: 'error' });
+    assert(errorsOnly.every(r => r.severity === 'error'), 'check_with_options() filters by severity');
+    const second = optionsText.indexOf('It was');
+    const ranged = proselint.check_with_options(optionsText, { range: { start: second, end: optionsText.length } });
+    assert(ranged.length > 0 && ranged.every(r => r.start >= second), 'check_with_options() lints only the range');
+    const badRange = JSON.parse(proselint.lint_with_options(optionsText, JSON.stringify({ range: { start: 0, end: 1000 } })));
+    assert('error' in badRange, 'lint_with_options() rejects out-of-bounds range');
+    try {
+      proselint.check_with_options(optionsText, { min_severity: 'fatal' });
+      assert(false, 'check_with_options() throws for invalid options');
+    } catch (e) {
+      assertEquals(e.name, 'InputError', 'Invalid options throw InputError');
+    }
+
+    console.log('\n📦 Mutable Configuration Tests\n');
+
+    // Test 24: toggling checks and settings in place
+    const mutable = new wasm.Proselint();
+    mutable.disable('weasel_words');
+    assert(!mutable.check('This is very bad.').some(r => r.check.startsWith('weasel_words')), 'disable() turns off a category');
+    mutable.enable('weasel_words');
+    mutable.set_severity('weasel_words', 'error');
+    const escalated = mutable.check('This is very bad.').filter(r => r.check.startsWith('weasel_words'));
+    assert(escalated.length > 0 && escalated.every(r => r.severity === 'error'), 'set_severity() changes reported severity');
+    try {
+      mutable.set_severity('weasel_words', 'fatal');
+      assert(false, 'set_severity() throws for unknown severity');
+    } catch (e) {
+      assertEquals(e.name, 'InputError', 'Unknown severity throws InputError');
+    }
+    mutable.set_max_errors(3);
+    mutable.set_check_quotes(false);
+    const current = JSON.parse(mutable.get_config());
+    assertEquals(current.max_errors, 3, 'get_config() reflects set_max_errors()');
+    assertEquals(current.check_quotes, false, 'get_config() reflects set_check_quotes()');
+    assertEquals(mutable.config().severities.weasel_words, 'error', 'config() returns an object');
+    mutable.reset();
+    assertEquals(JSON.parse(mutable.get_config()).max_errors, 0, 'reset() restores defaults');
+
+    console.log('\n📦 Lint Job Tests\n');
+
+    // Test 25: chunked linting with progress callbacks
+    const manuscript = 'The results were very unique... We tried to utilize it.\n\n'.repeat(500);
+    const job = proselint.lint_job(manuscript);
+    const reports = [];
+    job.on_progress(progress => reports.push(progress));
+    let steps = 0;
+    while (!job.step(1)) {
+      steps++;
+    }
+    assert(reports.length > 1, 'step() reports progress after each step');
+    assertEquals(reports[reports.length - 1].processed, manuscript.length, 'Last report covers the whole text');
+    assert(reports[reports.length - 1].done, 'Last report is marked done');
+    const reported = reports.reduce((sum, p) => sum + p.results.length, 0);
+    assertEquals(job.results().length, reported, 'Callbacks deliver every result');
+    assertEquals(job.progress(), 1, 'progress() reaches 1');
+
+    // Test 26: cancellation
+    const cancelled = proselint.lint_job(manuscript);
+    cancelled.step_chunks(1);
+    cancelled.cancel();
+    const partialCount = cancelled.results().length;
+    assert(cancelled.step(100), 'step() reports done after cancel()');
+    assertEquals(cancelled.results().length, partialCount, 'Cancelled job does no more work');
+    assert(cancelled.is_cancelled(), 'is_cancelled() is true after cancel()');
+
+    console.log('\n📦 Offset Encoding Tests\n');
+
+    // Test 27: UTF-16 offsets index JS strings directly
+    const wideText = '日本 👋 This is very bad.';
+    const wide = proselint.check_with_options(wideText, { utf16_offsets: true }).find(r => r.check === 'weasel_words.very');
+    assertEquals(wideText.slice(wide.utf16_start, wide.utf16_end), 'very', 'utf16_start/utf16_end slice the JS string');
+    assert(wide.start !== wide.utf16_start, 'Byte offsets differ from UTF-16 offsets after non-ASCII text');
+    const utf16Linter = wasm.Proselint.from_config({ utf16_offsets: true, char_offsets: true });
+    const both = utf16Linter.check(wideText).find(r => r.check === 'weasel_words.very');
+    assertEquals(Array.from(wideText).slice(both.char_start, both.char_end).join(''), 'very', 'char_start/char_end index code points');
+
+    // Test 28: End positions and LSP ranges
+    const longText = 'word '.repeat(30).trim() + '\n' + 'more '.repeat(20) + 'end.\n';
+    const sentence = proselint.check_with_options(longText, { categories: ['length'] }).find(r => r.check === 'length.sentence');
+    assertEquals([sentence.end_line, sentence.end_column].join(':'), '2:105', 'end_line/end_column mark the end of a multi-line match');
+    const [range] = wasm.Proselint.lsp_ranges(longText, [sentence], 'utf-16');
+    assertEquals(JSON.stringify(range), JSON.stringify({ start: { line: 0, character: 0 }, end: { line: 1, character: 104 } }), 'lsp_ranges returns zero-based ranges');
+    const [emojiRange] = wasm.Proselint.lsp_ranges(wideText, [wide]);
+    assertEquals(emojiRange.start.character, wide.utf16_start, 'lsp_ranges defaults to UTF-16');
+
+    console.log('\n📦 Allowlist Tests\n');
+
+    // Test 29: Ignore everywhere
+    const brandText = 'Very Good Ventures wrote a very long report.';
+    const brandLinter = new wasm.Proselint();
+    const countVery = () => brandLinter.check(brandText).filter(r => r.check === 'weasel_words.very').length;
+    assertEquals(countVery(), 2, 'Flags both uses of "very" before allowlisting');
+    brandLinter.allow('Very Good Ventures');
+    assertEquals(countVery(), 1, 'allow() suppresses results inside the phrase');
+    assertEquals(brandLinter.load_allowlist('# team words\n[weasel_words.very] very long\n'), 1, 'load_allowlist() returns the new entry count');
+    assertEquals(countVery(), 0, 'Word list entries suppress scoped results');
+    try {
+      brandLinter.allow_regex('(', undefined);
+      assert(false, 'allow_regex() throws for invalid patterns');
+    } catch (e) {
+      assertEquals(e.name, 'InputError', 'Invalid allowlist regex throws InputError');
+    }
+
+    console.log('\n📦 Baseline Tests\n');
+
+    // Test 30: Only new results are reported
+    const oldPage = 'It was a very bad day.\n\nThe team was very tired.';
+    const baseline = proselint.create_baseline([oldPage]);
+    assert(Object.keys(baseline.entries).length > 0, 'create_baseline() records fingerprints');
+    const movedPage = 'An introduction.\n\n' + oldPage;
+    assertEquals(proselint.check_new(movedPage, baseline).results.length, 0, 'Moved results stay in the baseline');
+    const editedPage = 'It was a bad day.\n\nThe team was very tired. The plan was very new.';
+    const report = proselint.check_new(editedPage, baseline);
+    assertEquals(report.results.filter(r => r.check === 'weasel_words.very').length, 1, 'check_new() reports only the new result');
+    assertEquals(report.stale, 1, 'check_new() counts fixed results as stale');
+    const matcher = new wasm.BaselineMatcher(baseline);
+    matcher.filter(editedPage, proselint.check(editedPage));
+    assertEquals(matcher.stale(), 1, 'BaselineMatcher counts stale entries');
+    assertEquals(Object.keys(matcher.pruned().entries).length, Object.keys(baseline.entries).length - 1, 'pruned() drops stale entries');
+
+    console.log('\n📦 Diff Tests\n');
+
+    // Test 31: Only changed lines are reported
+    const before = 'It was good.\n\nThe team was very tired.\n';
+    const after = 'It was very good.\n\nThe team was very tired.\n';
+    const changedOnly = proselint.check_changes(before, after).filter(r => r.check === 'weasel_words.very');
+    assertEquals(changedOnly.length, 1, 'check_changes() skips untouched lines');
+    assertEquals(changedOnly[0].line, 1, 'check_changes() reports the edited line');
+    const patch = '--- a/doc.md\n+++ b/doc.md\n@@ -1,3 +1,3 @@\n-It was good.\n+It was very good.\n \n The team was very tired.\n';
+    assertEquals(proselint.check_diff(after, patch).filter(r => r.check === 'weasel_words.very').length, 1, 'check_diff() reads added lines from a unified diff');
+    try {
+      proselint.check_diff(after, patch, 'other.md');
+      assert(false, 'check_diff() throws for a path the diff does not cover');
+    } catch (e) {
+      assertEquals(e.name, 'InputError', 'Unknown diff path throws InputError');
+    }
+
+    console.log('\n📦 Cache Tests\n');
+
+    // Test 32: Repeated paragraphs come from the cache
+    const cached = new Proselint();
+    assertEquals(cached.cache_stats(), undefined, 'cache_stats() is undefined until enabled');
+    cached.enable_cache();
+    const boilerplate = 'This is very important.\n\nIt was a very good day.';
+    const fresh = cached.check(boilerplate);
+    const reused = cached.check('Intro.\n\n' + boilerplate);
+    assertEquals(reused.length, fresh.length, 'Cached results are reused');
+    assertEquals(reused[0].line, fresh[0].line + 2, 'Cached results are moved to the new position');
+    const cacheStats = cached.cache_stats();
+    assertEquals(cacheStats.hits, 2, 'cache_stats() counts hits');
+    assert(cacheStats.hit_rate > 0 && cacheStats.hit_rate < 1, 'cache_stats() reports a hit rate');
+    cached.disable_cache();
+    assertEquals(cached.cache_stats(), undefined, 'disable_cache() drops the cache');
+
     console.log('\n📦 Line Ending Tests\n');
 
-    // Test 18: Unix line endings
+    // Test 33: Unix line endings
     const unixText = 'Line 1\nLine 2\nThis is very bad.';
     const unixResults = JSON.parse(proselint.lint(unixText));
     assert(unixResults.length > 0, 'Handles Unix line endings');
     assert(unixResults[0].line === 3, 'Correct line number with \\n');
 
-    // Test 19: Windows line endings
+    // Test 34: Windows line endings
     const winText = 'Line 1\r\nLine 2\r\nThis is very bad.';
     const winResults = JSON.parse(proselint.lint(winText));
     assert(winResults.length > 0, 'Handles Windows line endings');
     assert(winResults[0].line === 3, 'Correct line number with \\r\\n');
 
-    // Test 20: Mixed line endings
+    // Test 35: Mixed line endings
     const mixedText = 'Line 1\nLine 2\r\nThis is very bad.';
     const mixedResults = JSON.parse(proselint.lint(mixedText));
     assert(mixedResults.length > 0, 'Handles mixed line endings');